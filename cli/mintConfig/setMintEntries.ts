@@ -115,6 +115,7 @@ export const handler = async (
             symbol: symbol,
             name: name,
             uri: uri,
+            weight: null,
           })
           .accountsStrict({
             mintConfig: mintConfigId,
@@ -34,6 +34,9 @@ export const getArgs = (_connection: Connection, wallet: Wallet) => ({
       ],
       merkleTree: null,
       releaseAuthority: null,
+      randomnessSource: { slotHashes: undefined },
+      selectionMode: { random: undefined },
+      hiddenSettings: null,
     },
     mintPhases: [
       {
@@ -41,32 +44,26 @@ export const getArgs = (_connection: Connection, wallet: Wallet) => ({
           title: "Phase 0",
           subtitle: "Pre-sale",
         }),
-        startCondition: {
-          timeSeconds: new BN(START),
-          count: null,
-        },
-        endCondition: {
-          timeSeconds: new BN(START + 60 * 60 * 1),
-          count: null,
-        },
+        startCondition: { time: { seconds: new BN(START) } },
+        endCondition: { time: { seconds: new BN(START + 60 * 60 * 1) } },
         tokenChecks: [],
         authorization: {
           mode: { defaultDisallowed: undefined },
         },
+        priceCurve: null,
+        maxPerWallet: null,
+        maxSupply: null,
+        minted: new BN(0),
+        outputOverrides: null,
+        cooldownSeconds: null,
       },
       {
         metadata: JSON.stringify({
           title: "Phase 1",
           subtitle: "Allowlist 1 and Nifty Free NFT VIP Ticket Holders",
         }),
-        startCondition: {
-          timeSeconds: new BN(START + 60 * 60 * 1),
-          count: null,
-        },
-        endCondition: {
-          timeSeconds: new BN(START + 60 * 60 * 3),
-          count: null,
-        },
+        startCondition: { time: { seconds: new BN(START + 60 * 60 * 1) } },
+        endCondition: { time: { seconds: new BN(START + 60 * 60 * 3) } },
         tokenChecks: [
           {
             addressKind: { mint: undefined },
@@ -79,20 +76,20 @@ export const getArgs = (_connection: Connection, wallet: Wallet) => ({
         authorization: {
           mode: { defaultDisallowed: undefined },
         },
+        priceCurve: null,
+        maxPerWallet: null,
+        maxSupply: null,
+        minted: new BN(0),
+        outputOverrides: null,
+        cooldownSeconds: null,
       },
       {
         metadata: JSON.stringify({
           title: "Phase 2",
           subtitle: "Allowlist 2 | Collaborations and Project Partnerships",
         }),
-        startCondition: {
-          timeSeconds: new BN(START + 60 * 60 * 3),
-          count: null,
-        },
-        endCondition: {
-          timeSeconds: new BN(START + 60 * 60 * 5),
-          count: null,
-        },
+        startCondition: { time: { seconds: new BN(START + 60 * 60 * 3) } },
+        endCondition: { time: { seconds: new BN(START + 60 * 60 * 5) } },
         tokenChecks: [
           {
             addressKind: { mint: undefined },
@@ -105,16 +102,19 @@ export const getArgs = (_connection: Connection, wallet: Wallet) => ({
         authorization: {
           mode: { defaultDisallowed: undefined },
         },
+        priceCurve: null,
+        maxPerWallet: null,
+        maxSupply: null,
+        minted: new BN(0),
+        outputOverrides: null,
+        cooldownSeconds: null,
       },
       {
         metadata: JSON.stringify({
           title: "Phase 3",
           subtitle: "Public",
         }),
-        startCondition: {
-          timeSeconds: new BN(START + 60 * 60 * 5),
-          count: null,
-        },
+        startCondition: { time: { seconds: new BN(START + 60 * 60 * 5) } },
         endCondition: null,
         tokenChecks: [
           {
@@ -126,9 +126,19 @@ export const getArgs = (_connection: Connection, wallet: Wallet) => ({
           },
         ],
         authorization: null,
+        priceCurve: null,
+        maxPerWallet: null,
+        maxSupply: null,
+        minted: new BN(0),
+        outputOverrides: null,
+        cooldownSeconds: null,
       },
     ],
     metadata: JSON.stringify({}),
+    maxPerWalletTotal: null,
+    pauseAuthority: null,
+    phaseSelection: { first: undefined },
+    botTaxLamports: null,
   },
   dryRun: false,
 });
@@ -0,0 +1,144 @@
+use crate::errors::ErrorCode;
+use crate::state::MintPhase;
+use anchor_lang::prelude::*;
+
+pub const MAX_CONDITION_DEPTH: usize = 4;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum MintPhaseCondition {
+    All { conditions: Vec<MintPhaseCondition> }, // met when every condition is met
+    Any { conditions: Vec<MintPhaseCondition> }, // met when at least one condition is met
+    Not { conditions: Vec<MintPhaseCondition> }, // met when none of the conditions are met (NOTE: a list instead of a box so it can be described in the IDL)
+    Time { seconds: i64 },                       // met once the clock reaches the timestamp in seconds
+    Count { count: u64 },                        // met once the mint config count reaches the count
+    Slot { slot: u64 },                          // met once the clock reaches the slot (NOTE: not affected by validator clock drift)
+    PhaseSoldOut { mint_phase_index: u8 },       // met once the phase has minted its max supply
+}
+
+// state a condition is evaluated against
+pub struct ConditionContext<'a> {
+    pub timestamp: i64,
+    pub slot: u64,
+    pub count: u64,
+    pub mint_phases: &'a [MintPhase],
+}
+
+impl MintPhaseCondition {
+    pub fn check_valid(&self) -> Result<()> {
+        if self.depth() > MAX_CONDITION_DEPTH {
+            return err!(ErrorCode::InvalidPhaseCondition);
+        }
+        Ok(())
+    }
+
+    pub fn depth(&self) -> usize {
+        match self {
+            MintPhaseCondition::All { conditions } | MintPhaseCondition::Any { conditions } | MintPhaseCondition::Not { conditions } => {
+                conditions.iter().map(|c| c.depth()).max().unwrap_or(0).checked_add(1).expect("Add error")
+            }
+            _ => 1,
+        }
+    }
+
+    pub fn evaluate(&self, context: &ConditionContext) -> bool {
+        match self {
+            MintPhaseCondition::All { conditions } => conditions.iter().all(|c| c.evaluate(context)),
+            MintPhaseCondition::Any { conditions } => conditions.iter().any(|c| c.evaluate(context)),
+            MintPhaseCondition::Not { conditions } => !conditions.iter().any(|c| c.evaluate(context)),
+            MintPhaseCondition::Time { seconds } => context.timestamp >= *seconds,
+            MintPhaseCondition::Count { count } => context.count >= *count,
+            MintPhaseCondition::Slot { slot } => context.slot >= *slot,
+            MintPhaseCondition::PhaseSoldOut { mint_phase_index } => match context.mint_phases.get(usize::from(*mint_phase_index)) {
+                Some(mint_phase) => mint_phase.max_supply.map_or(false, |max_supply| mint_phase.minted >= max_supply),
+                None => false,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(mint_phases: &[MintPhase]) -> ConditionContext {
+        ConditionContext {
+            timestamp: 1_000,
+            slot: 500,
+            count: 10,
+            mint_phases,
+        }
+    }
+
+    #[test]
+    fn test_leaves() {
+        let context = context(&[]);
+        assert!(MintPhaseCondition::Time { seconds: 1_000 }.evaluate(&context));
+        assert!(!MintPhaseCondition::Time { seconds: 1_001 }.evaluate(&context));
+        assert!(MintPhaseCondition::Count { count: 10 }.evaluate(&context));
+        assert!(!MintPhaseCondition::Count { count: 11 }.evaluate(&context));
+        assert!(MintPhaseCondition::Slot { slot: 499 }.evaluate(&context));
+        assert!(!MintPhaseCondition::Slot { slot: 501 }.evaluate(&context));
+    }
+
+    #[test]
+    fn test_phase_sold_out() {
+        let mint_phases = vec![
+            MintPhase {
+                max_supply: Some(5),
+                minted: 5,
+                ..MintPhase::default()
+            },
+            MintPhase {
+                max_supply: Some(5),
+                minted: 4,
+                ..MintPhase::default()
+            },
+            MintPhase { minted: 100, ..MintPhase::default() },
+        ];
+        let context = context(&mint_phases);
+        assert!(MintPhaseCondition::PhaseSoldOut { mint_phase_index: 0 }.evaluate(&context));
+        assert!(!MintPhaseCondition::PhaseSoldOut { mint_phase_index: 1 }.evaluate(&context));
+        // phases without a max supply never sell out
+        assert!(!MintPhaseCondition::PhaseSoldOut { mint_phase_index: 2 }.evaluate(&context));
+        assert!(!MintPhaseCondition::PhaseSoldOut { mint_phase_index: 3 }.evaluate(&context));
+    }
+
+    #[test]
+    fn test_composition() {
+        let context = context(&[]);
+        let met = || MintPhaseCondition::Time { seconds: 0 };
+        let unmet = || MintPhaseCondition::Count { count: 100 };
+
+        assert!(MintPhaseCondition::All { conditions: vec![met(), met()] }.evaluate(&context));
+        assert!(!MintPhaseCondition::All { conditions: vec![met(), unmet()] }.evaluate(&context));
+        assert!(MintPhaseCondition::Any { conditions: vec![unmet(), met()] }.evaluate(&context));
+        assert!(!MintPhaseCondition::Any { conditions: vec![unmet(), unmet()] }.evaluate(&context));
+        assert!(MintPhaseCondition::Not { conditions: vec![unmet()] }.evaluate(&context));
+        assert!(!MintPhaseCondition::Not { conditions: vec![unmet(), met()] }.evaluate(&context));
+
+        // empty lists
+        assert!(MintPhaseCondition::All { conditions: vec![] }.evaluate(&context));
+        assert!(!MintPhaseCondition::Any { conditions: vec![] }.evaluate(&context));
+
+        // time and count both required
+        let both = MintPhaseCondition::All {
+            conditions: vec![MintPhaseCondition::Time { seconds: 900 }, MintPhaseCondition::Count { count: 20 }],
+        };
+        assert!(!both.evaluate(&context));
+    }
+
+    #[test]
+    fn test_depth() {
+        let mut condition = MintPhaseCondition::Time { seconds: 0 };
+        for _ in 0..MAX_CONDITION_DEPTH - 1 {
+            condition = MintPhaseCondition::Not { conditions: vec![condition] };
+        }
+        assert!(condition.check_valid().is_ok());
+        condition = MintPhaseCondition::Any { conditions: vec![condition] };
+        assert!(condition.check_valid().is_err());
+
+        // serializes round trip with nesting
+        let decoded = MintPhaseCondition::try_from_slice(&condition.try_to_vec().unwrap()).unwrap();
+        assert_eq!(decoded.depth(), MAX_CONDITION_DEPTH + 1);
+    }
+}
@@ -11,6 +11,18 @@ pub enum ErrorCode {
     InvalidIndex,
     #[msg("Invalid program id")]
     InvalidProgramId,
+    #[msg("Invalid mint entry weight")]
+    InvalidMintEntryWeight,
+    #[msg("Mint entry field too long")]
+    MintEntryFieldTooLong,
+    #[msg("Invalid mint entry data")]
+    InvalidMintEntryData,
+    #[msg("Invalid pending mint")]
+    InvalidPendingMint,
+    #[msg("Invalid hidden mint")]
+    InvalidHiddenMint,
+    #[msg("Invalid price curve")]
+    InvalidPriceCurve,
 
     // mint config errors
     #[msg("Too many creators")]
@@ -21,6 +33,18 @@ pub enum ErrorCode {
     InvalidTokenStandard,
     #[msg("Cannot mint with programmably nft and merkle tree")]
     ProgrammableAndMerkleTree,
+    #[msg("Cannot use sequential selection with a deferred randomness source")]
+    SequentialAndRandomnessSource,
+    #[msg("Mint entries are locked by the provenance hash")]
+    ProvenanceHashSet,
+    #[msg("Provenance hash not set")]
+    ProvenanceHashNotSet,
+    #[msg("Provenance hash does not match mint entries")]
+    ProvenanceHashMismatch,
+    #[msg("Cannot mint hidden tokens into a merkle tree")]
+    HiddenAndMerkleTree,
+    #[msg("Invalid hidden settings")]
+    InvalidHiddenSettings,
 
     // mint errors
     #[msg("Minting already started")]
@@ -31,6 +55,18 @@ pub enum ErrorCode {
     PhaseNotActive,
     #[msg("No tokens remaining")]
     NotTokensRemaining,
+    #[msg("Mint must be revealed in a later slot than it was committed")]
+    RevealTooEarly,
+    #[msg("Invalid vrf randomness account")]
+    InvalidVrfRandomness,
+    #[msg("Vrf randomness not fulfilled")]
+    VrfRandomnessNotFulfilled,
+    #[msg("Merkle mint entry does not match the picked index")]
+    InvalidMerkleMintEntry,
+    #[msg("Invalid merkle proof")]
+    InvalidMerkleProof,
+    #[msg("Mint limit per wallet reached")]
+    MintLimitReached,
 
     // token check
     #[msg("Holder must be signer")]
@@ -51,10 +87,58 @@ pub enum ErrorCode {
     MintPhaseAuthorizationsUsed = 40,
     #[msg("IncorrectAuthorizationHolder")]
     IncorrectAuthorizationHolder,
+    #[msg("Invalid mint counter")]
+    InvalidMintCounter,
+    #[msg("Invalid mint cooldown")]
+    InvalidMintCooldown,
+    #[msg("User must be signer to mint with per wallet limits or a deferred reveal")]
+    UserNotSigner,
 
     // release time
     #[msg("Release time invalid")]
     ReleaseTimeInvalid = 50,
     #[msg("Invalid output mints pending release")]
     InvalidOutputMintsPendingRelease,
+
+    // mint entry storage
+    #[msg("Invalid mint entry storage")]
+    InvalidMintEntryStorage = 60,
+    #[msg("Invalid mint entry template")]
+    InvalidMintEntryTemplate,
+    #[msg("Invalid mint entry page")]
+    InvalidMintEntryPage,
+    #[msg("Merkle entries picked at random need a deferred randomness source")]
+    MerkleAndRandomnessSource,
+    #[msg("Paged entries picked at random need a deferred randomness source")]
+    PagedAndRandomnessSource,
+
+    // phase
+    #[msg("Invalid phase condition")]
+    InvalidPhaseCondition = 70,
+    #[msg("Phase ended")]
+    PhaseEnded,
+    #[msg("Phase sold out")]
+    PhaseSoldOut,
+    #[msg("No active phase")]
+    NoActivePhase,
+    #[msg("Phase not started")]
+    PhaseNotStarted,
+
+    // mint guards
+    #[msg("Minting is paused")]
+    MintPaused = 80,
+    #[msg("Mint cooldown has not passed")]
+    MintCooldownActive,
+
+    // pending mint
+    #[msg("Pending mint expired and can only be released with expire_pending_mint")]
+    PendingMintExpired = 90,
+    #[msg("Pending mint has not expired")]
+    PendingMintNotExpired,
+    #[msg("Pending mint randomness already fulfilled")]
+    PendingMintAlreadyFulfilled,
+
+    // migration
+    #[msg("Mint config is not a legacy mint config")]
+    InvalidLegacyMintConfig = 100,
 }
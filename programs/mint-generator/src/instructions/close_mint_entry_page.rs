@@ -0,0 +1,18 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseMintEntryPageCtx<'info> {
+    // only pages with nothing left to mint can be closed
+    #[account(mut, close = authority, constraint = mint_entry_page.mint_config == mint_config.key() && mint_entry_page.count == mint_entry_page.supply @ ErrorCode::InvalidMintEntryPage)]
+    mint_entry_page: Box<Account<'info, MintEntryPage>>,
+    #[account(constraint = mint_config.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    mint_config: Box<Account<'info, MintConfig>>,
+    #[account(mut)]
+    authority: Signer<'info>,
+}
+
+pub fn handler(_ctx: Context<CloseMintEntryPageCtx>) -> Result<()> {
+    Ok(())
+}
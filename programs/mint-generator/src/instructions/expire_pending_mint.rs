@@ -0,0 +1,28 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExpirePendingMintCtx<'info> {
+    #[account(mut)]
+    mint_config: Box<Account<'info, MintConfig>>,
+    #[account(mut, close = collector, constraint = pending_mint.mint_config == mint_config.key() @ ErrorCode::InvalidPendingMint)]
+    pending_mint: Box<Account<'info, PendingMint>>,
+    /// CHECK: Address is checked
+    #[account(mut, address = mint_config.authority @ ErrorCode::InvalidAuthority)]
+    collector: UncheckedAccount<'info>,
+}
+
+// anyone can release the token reserved by a commit that was not revealed in its window
+// NOTE: the commit is forfeited - the payment, the phase supply it used, authorization uses and per wallet counters and cooldowns are kept and the rent goes to the collector
+pub fn handler(ctx: Context<ExpirePendingMintCtx>) -> Result<()> {
+    if !ctx.accounts.pending_mint.is_expired(Clock::get()?.slot) {
+        return Err(error!(ErrorCode::PendingMintNotExpired));
+    }
+    msg!("Pending mint from slot {} forfeited", ctx.accounts.pending_mint.commit_slot);
+
+    // the reserved token goes back to the remaining supply
+    let mint_config = &mut ctx.accounts.mint_config;
+    mint_config.pending_reveals = mint_config.pending_reveals.saturating_sub(1);
+    Ok(())
+}
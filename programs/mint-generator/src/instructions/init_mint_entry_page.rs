@@ -0,0 +1,67 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::resize_account;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitMintEntryPageIx {
+    page_index: u32,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: InitMintEntryPageIx)]
+pub struct InitMintEntryPageCtx<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = MINT_ENTRY_PAGE_HEADER_SIZE,
+        seeds = [MINT_ENTRY_PAGE_PREFIX.as_bytes(), mint_config.key().as_ref(), ix.page_index.to_le_bytes().as_ref()],
+        bump
+    )]
+    mint_entry_page: Box<Account<'info, MintEntryPage>>,
+    #[account(mut, constraint = mint_config.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    mint_config: Box<Account<'info, MintConfig>>,
+    authority: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<InitMintEntryPageCtx>, ix: InitMintEntryPageIx) -> Result<()> {
+    let mint_config = &mut ctx.accounts.mint_config;
+    if mint_config.count > 0 || mint_config.pending_reveals > 0 {
+        return Err(error!(ErrorCode::MintingAlreadyStarted));
+    }
+    if mint_config.provenance_hash.is_some() {
+        return Err(error!(ErrorCode::ProvenanceHashSet));
+    }
+    // the first page switches an empty mint config to paged storage
+    let mut page_remaining = match &mint_config.entry_storage {
+        MintEntryStorage::Paged { page_remaining } => page_remaining.clone(),
+        MintEntryStorage::Inline if mint_config.supply == 0 => vec![],
+        _ => return Err(error!(ErrorCode::InvalidMintEntryStorage)),
+    };
+    // pages are added in order so they can be passed in order
+    if ix.page_index as usize != page_remaining.len() {
+        return Err(error!(ErrorCode::InvalidMintEntryPage));
+    }
+
+    let mint_entry_page = &mut ctx.accounts.mint_entry_page;
+    mint_entry_page.bump = *ctx.bumps.get("mint_entry_page").unwrap();
+    mint_entry_page.mint_config = mint_config.key();
+    mint_entry_page.page_index = ix.page_index;
+    mint_entry_page.supply = 0;
+    mint_entry_page.count = 0;
+
+    page_remaining.push(0);
+    mint_config.entry_storage = MintEntryStorage::Paged { page_remaining };
+    mint_config.check_valid()?;
+    resize_account(
+        &mut mint_config.to_account_info(),
+        mint_config.account_size(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    Ok(())
+}
@@ -1,16 +1,33 @@
 use crate::errors::ErrorCode;
-use crate::state::get_phase;
 use crate::state::get_random_output_mint;
 use crate::state::output_mint_bg_creators;
 use crate::state::output_mint_mpl_creators;
+use crate::state::validate_phase;
+use crate::state::HiddenMint;
+use crate::state::MerkleMintEntry;
 use crate::state::MintConfig;
+use crate::state::MintCooldown;
+use crate::state::MintCounter;
 use crate::state::MintEntry;
 use crate::state::MintPhase;
 use crate::state::MintPhaseAuthorization;
 use crate::state::MintPhaseAuthorizationMode;
 use crate::state::MintPhaseTokenCheckAddressKind;
 use crate::state::MintPhaseTokenCheckMode;
+use crate::state::MintValidation;
+use crate::state::MintWalletCounter;
+use crate::state::OutputMintConfig;
 use crate::state::OutputMintPendingRelease;
+use crate::state::PendingMint;
+use crate::state::RandomnessSource;
+use crate::state::HIDDEN_MINT_SIZE;
+use crate::state::MINT_COOLDOWN_SIZE;
+use crate::state::MINT_COUNTER_SIZE;
+use crate::state::MINT_WALLET_COUNTER_SIZE;
+use crate::state::PENDING_MINT_SIZE;
+use crate::state::VRF_REQUEST_RANDOMNESS_DISCRIMINATOR;
+use crate::utils::create_pda_account;
+use crate::utils::pseudo_random_number;
 use crate::utils::resize_account;
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token;
@@ -36,6 +53,7 @@ use mpl_token_metadata::state::Collection;
 use mpl_token_metadata::state::Data;
 use mpl_token_metadata::state::Metadata;
 use mpl_token_metadata::state::PrintSupply;
+use solana_program::hash::hashv;
 use solana_program::instruction::Instruction;
 use solana_program::program::invoke;
 use solana_program::program::invoke_signed;
@@ -47,14 +65,16 @@ use std::slice::Iter;
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct MintIx {
     mint_phase_ix: u8,
+    // entry and proof for the next entry when using merkle storage
+    merkle_mint_entry: Option<MerkleMintEntry>,
 }
 
 #[derive(Accounts)]
 pub struct MintCtx<'info> {
     #[account(mut)]
-    mint_config: Box<Account<'info, MintConfig>>,
+    pub mint_config: Box<Account<'info, MintConfig>>,
     /// CHECK: Target account is unchecked
-    user: UncheckedAccount<'info>,
+    pub user: UncheckedAccount<'info>,
     #[account(mut)]
     payer: Signer<'info>,
     /// CHECK: Address is checked
@@ -67,53 +87,210 @@ pub struct MintCtx<'info> {
 }
 
 pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, MintCtx<'info>>, ix: MintIx) -> Result<()> {
-    let remaining_accounts = &mut ctx.remaining_accounts.iter();
-    let mint_phase = get_phase(&ctx.accounts.mint_config, ix.mint_phase_ix, ctx.accounts.payer.key() == ctx.accounts.mint_config.authority)?;
+    handle_mint(ctx.program_id, ctx.accounts, ix.mint_phase_ix, ix.merkle_mint_entry, &mut ctx.remaining_accounts.iter())?;
+    Ok(())
+}
 
-    // token checks
-    handle_token_checks(mint_phase, remaining_accounts, ctx.accounts.payer.to_account_info(), ctx.accounts.system_program.to_account_info())?;
+// returns whether a token was minted or committed - false when the bot tax was charged instead
+pub fn handle_mint<'info>(
+    program_id: &Pubkey,
+    accounts: &mut MintCtx<'info>,
+    mint_phase_ix: u8,
+    merkle_mint_entry: Option<MerkleMintEntry>,
+    remaining_accounts: &mut Iter<AccountInfo<'info>>,
+) -> Result<bool> {
+    if accounts.mint_config.paused {
+        return Err(error!(ErrorCode::MintPaused));
+    }
+    if accounts.mint_config.available_tokens() == 0 {
+        return Err(error!(ErrorCode::NotTokensRemaining));
+    }
 
-    // check authorization record
-    handle_authorization_checks(ctx.program_id, &ctx.accounts.mint_config, ix.mint_phase_ix, mint_phase, remaining_accounts)?;
+    // every taxable check is validated before any of them are executed
+    let is_authority = accounts.payer.key() == accounts.mint_config.authority;
+    let validated_mint = match validate_mint(&accounts.mint_config, mint_phase_ix, is_authority, remaining_accounts)? {
+        MintValidation::Valid(validated_mint) => validated_mint,
+        MintValidation::Invalid(error_code) => {
+            handle_bot_tax(accounts, error_code)?;
+            return Ok(false);
+        }
+    };
+    execute_token_checks(validated_mint.token_check_actions, accounts.payer.to_account_info(), accounts.system_program.to_account_info())?;
+    if let Some(mint_phase_authorization) = validated_mint.mint_phase_authorization {
+        mint_phase_authorization.exit(program_id)?;
+    }
+    let mint_phase = &accounts.mint_config.mint_phases[usize::from(mint_phase_ix)];
+
+    // check time since the users last mint
+    handle_mint_cooldown(
+        program_id,
+        &accounts.mint_config,
+        mint_phase_ix,
+        mint_phase,
+        accounts.user.to_account_info(),
+        accounts.payer.to_account_info(),
+        accounts.system_program.to_account_info(),
+        remaining_accounts,
+    )?;
 
-    // get mint entry
-    let output_mint_entry = get_random_output_mint(&mut ctx.accounts.mint_config, &ctx.accounts.recent_slothashes)?;
+    // check per wallet limits
+    handle_mint_counter(
+        program_id,
+        &accounts.mint_config,
+        mint_phase_ix,
+        mint_phase,
+        accounts.user.to_account_info(),
+        accounts.payer.to_account_info(),
+        accounts.system_program.to_account_info(),
+        remaining_accounts,
+    )?;
+    handle_mint_wallet_counter(
+        program_id,
+        &accounts.mint_config,
+        accounts.user.to_account_info(),
+        accounts.payer.to_account_info(),
+        accounts.system_program.to_account_info(),
+        remaining_accounts,
+    )?;
+
+    // count towards the phase supply
+    let mint_phase = &mut accounts.mint_config.mint_phases[usize::from(mint_phase_ix)];
+    mint_phase.minted = mint_phase.minted.checked_add(1).expect("Add error");
+
+    // commit now and pick the entry in `reveal_mint` once the randomness is available
+    if accounts.mint_config.output_mint_config.randomness_source != RandomnessSource::SlotHashes {
+        handle_mint_commit(
+            &mut accounts.mint_config,
+            mint_phase_ix,
+            accounts.user.to_account_info(),
+            accounts.payer.to_account_info(),
+            accounts.system_program.to_account_info(),
+            remaining_accounts,
+        )?;
+        return Ok(true);
+    }
+
+    // get mint entry - mint entry pages are passed here when using paged storage
+    let output_mint_entry = get_random_output_mint(&mut accounts.mint_config, pseudo_random_number(&accounts.recent_slothashes)?, merkle_mint_entry, remaining_accounts)?;
 
     // mint
-    if ctx.accounts.mint_config.output_mint_config.merkle_tree.is_some() {
+    let output_mint_config = accounts.mint_config.phase_output_mint_config(mint_phase_ix);
+    if output_mint_config.merkle_tree.is_some() {
         handle_mint_cnft(
             output_mint_entry,
-            &ctx.accounts.mint_config,
-            ctx.accounts.user.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
+            &accounts.mint_config,
+            &output_mint_config,
+            accounts.user.to_account_info(),
+            accounts.payer.to_account_info(),
+            accounts.system_program.to_account_info(),
             remaining_accounts,
         )?;
     } else {
         handle_mint_nft(
             output_mint_entry,
-            &ctx.accounts.mint_config,
-            ctx.accounts.user.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
-            ctx.accounts.system_program.to_account_info(),
+            &accounts.mint_config,
+            &output_mint_config,
+            mint_phase_ix,
+            accounts.user.to_account_info(),
+            accounts.payer.to_account_info(),
+            accounts.system_program.to_account_info(),
             remaining_accounts,
         )?;
     }
 
     // resize mint config last
     resize_account(
-        &mut ctx.accounts.mint_config.to_account_info(),
-        ctx.accounts.mint_config.account_size(),
-        &ctx.accounts.collector,
-        &ctx.accounts.system_program,
+        &mut accounts.mint_config.to_account_info(),
+        accounts.mint_config.account_size(),
+        &accounts.collector,
+        &accounts.system_program,
     )?;
 
-    Ok(())
+    Ok(true)
+}
+
+// charge the bot tax and end the mint without minting - fails instead when no bot tax is set
+pub fn handle_bot_tax(accounts: &MintCtx, error_code: ErrorCode) -> Result<()> {
+    match accounts.mint_config.bot_tax_lamports {
+        Some(bot_tax_lamports) => {
+            msg!("Bot tax charged: {}", error_code);
+            invoke(
+                &transfer(&accounts.payer.key(), &accounts.collector.key(), bot_tax_lamports),
+                &[accounts.payer.to_account_info(), accounts.collector.to_account_info(), accounts.system_program.to_account_info()],
+            )
+        }
+        None => Err(error!(error_code)),
+    }
+}
+
+// checks of a valid mint that are executed once everything is validated
+pub struct ValidatedMint<'info> {
+    pub token_check_actions: Vec<TokenCheckAction<'info>>,
+    // updated authorization record to save
+    pub mint_phase_authorization: Option<Account<'info, MintPhaseAuthorization>>,
+}
+
+// validate the phase, token checks and authorization record
+// NOTE: missing or malformed accounts are still raised as errors
+pub fn validate_mint<'info>(
+    mint_config: &Account<'info, MintConfig>,
+    mint_phase_ix: u8,
+    is_authority: bool,
+    remaining_accounts: &mut Iter<AccountInfo<'info>>,
+) -> Result<MintValidation<ValidatedMint<'info>>> {
+    let mint_phase = match validate_phase(mint_config, mint_phase_ix, is_authority)? {
+        MintValidation::Valid(mint_phase) => mint_phase,
+        MintValidation::Invalid(error_code) => return Ok(MintValidation::Invalid(error_code)),
+    };
+
+    // token checks - committed mints have already claimed their token so they count towards the price
+    let mint_count = mint_config.count.saturating_add(mint_config.pending_reveals);
+    let token_check_actions = match validate_token_checks(mint_phase, mint_count, remaining_accounts)? {
+        MintValidation::Valid(token_check_actions) => token_check_actions,
+        MintValidation::Invalid(error_code) => return Ok(MintValidation::Invalid(error_code)),
+    };
+
+    // check authorization record
+    let mint_phase_authorization = match validate_authorization_checks(mint_config, mint_phase_ix, mint_phase, remaining_accounts)? {
+        MintValidation::Valid(mint_phase_authorization) => mint_phase_authorization,
+        MintValidation::Invalid(error_code) => return Ok(MintValidation::Invalid(error_code)),
+    };
+
+    Ok(MintValidation::Valid(ValidatedMint {
+        token_check_actions,
+        mint_phase_authorization,
+    }))
+}
+
+// tokens moved by a valid token check
+pub enum TokenCheckAction<'info> {
+    TransferSol {
+        holder: AccountInfo<'info>,
+        transfer_target: AccountInfo<'info>,
+        amount: u64,
+    },
+    TransferToken {
+        holder: AccountInfo<'info>,
+        holder_token_account: AccountInfo<'info>,
+        transfer_target: AccountInfo<'info>,
+        target_token_account: AccountInfo<'info>,
+        target_mint: AccountInfo<'info>,
+        token_program: AccountInfo<'info>,
+        amount: u64,
+    },
+    Burn {
+        holder: AccountInfo<'info>,
+        holder_token_account: AccountInfo<'info>,
+        mint: AccountInfo<'info>,
+        token_program: AccountInfo<'info>,
+        amount: u64,
+    },
 }
 
-pub fn handle_token_checks<'info>(mint_phase: &MintPhase, remaining_accounts: &mut Iter<AccountInfo<'info>>, payer: AccountInfo<'info>, system_program: AccountInfo<'info>) -> Result<()> {
+pub fn validate_token_checks<'info>(mint_phase: &MintPhase, mint_count: u64, remaining_accounts: &mut Iter<AccountInfo<'info>>) -> Result<MintValidation<Vec<TokenCheckAction<'info>>>> {
+    let mut token_check_actions = Vec::new();
     if mint_phase.token_checks.is_empty() {
-        return Ok(());
+        return Ok(MintValidation::Valid(token_check_actions));
     }
     // get token program
     let token_program = next_account_info(remaining_accounts)?;
@@ -126,10 +303,12 @@ pub fn handle_token_checks<'info>(mint_phase: &MintPhase, remaining_accounts: &m
         return Err(error!(ErrorCode::InvalidProgramId));
     }
 
+    let timestamp = Clock::get()?.unix_timestamp;
     for token_check in &mint_phase.token_checks {
+        let amount = mint_phase.token_check_amount(token_check, timestamp, mint_count);
         let holder = next_account_info(remaining_accounts)?;
         if !holder.is_signer {
-            return Err(error!(ErrorCode::HolderNotSigner));
+            return Ok(MintValidation::Invalid(ErrorCode::HolderNotSigner));
         }
 
         let holder_token_account = match token_check.address_kind {
@@ -140,7 +319,7 @@ pub fn handle_token_checks<'info>(mint_phase: &MintPhase, remaining_accounts: &m
                     let token_account_info = next_account_info(remaining_accounts)?;
                     let token_account = Account::<TokenAccount>::try_from(token_account_info)?;
                     if token_account.owner != holder.key() || token_account.mint != token_check.address.key() {
-                        return Err(error!(ErrorCode::InvalidTokenCheckHolderTokenAccount));
+                        return Ok(MintValidation::Invalid(ErrorCode::InvalidTokenCheckHolderTokenAccount));
                     }
                     token_account_info
                 }
@@ -149,20 +328,20 @@ pub fn handle_token_checks<'info>(mint_phase: &MintPhase, remaining_accounts: &m
                 let token_account_info = next_account_info(remaining_accounts)?;
                 let token_account: Account<TokenAccount> = Account::<TokenAccount>::try_from(token_account_info)?;
                 if token_account.owner != holder.key() {
-                    return Err(error!(ErrorCode::InvalidTokenCheckHolderTokenAccount));
+                    return Ok(MintValidation::Invalid(ErrorCode::InvalidTokenCheckHolderTokenAccount));
                 }
 
                 let metadata_account_info = next_account_info(remaining_accounts)?;
                 if metadata_account_info.to_account_info().owner.key() != mpl_token_metadata::id() {
-                    return Err(error!(ErrorCode::InvalidMintMetadataOwner));
+                    return Ok(MintValidation::Invalid(ErrorCode::InvalidMintMetadataOwner));
                 }
                 let metadata: Metadata = Metadata::deserialize(&mut metadata_account_info.try_borrow_mut_data().expect("Failed to borrow data").as_ref()).expect("Failed to deserialize metadata");
                 if metadata.mint != token_account.mint.key() || metadata.collection.is_none() {
-                    return Err(error!(ErrorCode::InvalidMintMetadata));
+                    return Ok(MintValidation::Invalid(ErrorCode::InvalidMintMetadata));
                 }
                 let collection = metadata.collection.unwrap();
                 if !collection.verified || collection.key != token_check.address {
-                    return Err(error!(ErrorCode::InvalidMintMetadata));
+                    return Ok(MintValidation::Invalid(ErrorCode::InvalidMintMetadata));
                 }
                 token_account_info
             }
@@ -170,19 +349,19 @@ pub fn handle_token_checks<'info>(mint_phase: &MintPhase, remaining_accounts: &m
                 let token_account_info = next_account_info(remaining_accounts)?;
                 let token_account: Account<TokenAccount> = Account::<TokenAccount>::try_from(token_account_info)?;
                 if token_account.owner != holder.key() {
-                    return Err(error!(ErrorCode::InvalidTokenCheckHolderTokenAccount));
+                    return Ok(MintValidation::Invalid(ErrorCode::InvalidTokenCheckHolderTokenAccount));
                 }
 
                 let metadata_account_info = next_account_info(remaining_accounts)?;
                 if metadata_account_info.to_account_info().owner.key() != mpl_token_metadata::id() {
-                    return Err(error!(ErrorCode::InvalidMintMetadataOwner));
+                    return Ok(MintValidation::Invalid(ErrorCode::InvalidMintMetadataOwner));
                 }
                 let metadata: Metadata = Metadata::deserialize(&mut metadata_account_info.try_borrow_mut_data().expect("Failed to borrow data").as_ref()).expect("Failed to deserialize metadata");
                 if metadata.mint != token_account.mint.key() {
-                    return Err(error!(ErrorCode::InvalidMintMetadata));
+                    return Ok(MintValidation::Invalid(ErrorCode::InvalidMintMetadata));
                 }
                 if metadata.data.creators.is_none() || metadata.data.creators.unwrap().iter().find(|c| c.verified && c.address == token_check.address).is_none() {
-                    return Err(error!(ErrorCode::InvalidMintMetadata));
+                    return Ok(MintValidation::Invalid(ErrorCode::InvalidMintMetadata));
                 }
                 token_account_info
             }
@@ -192,57 +371,100 @@ pub fn handle_token_checks<'info>(mint_phase: &MintPhase, remaining_accounts: &m
             MintPhaseTokenCheckMode::Check => {
                 // holder token account already checked above
                 let holder_token_account = Account::<TokenAccount>::try_from(holder_token_account)?;
-                if holder_token_account.amount < token_check.amount {
-                    return Err(error!(ErrorCode::InvalidTokenCheck));
+                if holder_token_account.amount < amount {
+                    return Ok(MintValidation::Invalid(ErrorCode::InvalidTokenCheck));
                 }
             }
             MintPhaseTokenCheckMode::Transfer => {
+                let transfer_target_account_info = next_account_info(remaining_accounts)?;
+                if token_check.transfer_target.is_none() || transfer_target_account_info.key() != token_check.transfer_target.unwrap() {
+                    return Ok(MintValidation::Invalid(ErrorCode::InvalidTokenCheckTransferTarget));
+                }
                 if token_check.address == Pubkey::default() {
-                    let transfer_target_account_info = next_account_info(remaining_accounts)?;
-                    if token_check.transfer_target.is_none() || transfer_target_account_info.key() != token_check.transfer_target.unwrap() {
-                        return Err(error!(ErrorCode::InvalidTokenCheckTransferTarget));
-                    }
-                    invoke(
-                        &transfer(&holder.key(), &transfer_target_account_info.key(), token_check.amount),
-                        &[holder.to_account_info(), transfer_target_account_info.to_account_info(), system_program.to_account_info()],
-                    )?;
+                    token_check_actions.push(TokenCheckAction::TransferSol {
+                        holder: holder.to_account_info(),
+                        transfer_target: transfer_target_account_info.to_account_info(),
+                        amount,
+                    });
                 } else {
-                    let transfer_target_account_info = next_account_info(remaining_accounts)?;
-                    if token_check.transfer_target.is_none() || transfer_target_account_info.key() != token_check.transfer_target.unwrap() {
-                        return Err(error!(ErrorCode::InvalidTokenCheckTransferTarget));
-                    }
                     let target_token_account_info = next_account_info(remaining_accounts)?;
                     let target_mint_account_info = next_account_info(remaining_accounts)?;
-                    let cpi_accounts = associated_token::Create {
-                        mint: target_mint_account_info.to_account_info(),
-                        payer: payer.to_account_info(),
-                        authority: transfer_target_account_info.to_account_info(),
-                        associated_token: target_token_account_info.to_account_info(),
-                        system_program: system_program.to_account_info(),
+                    token_check_actions.push(TokenCheckAction::TransferToken {
+                        holder: holder.to_account_info(),
+                        holder_token_account: holder_token_account.to_account_info(),
+                        transfer_target: transfer_target_account_info.to_account_info(),
+                        target_token_account: target_token_account_info.to_account_info(),
+                        target_mint: target_mint_account_info.to_account_info(),
                         token_program: token_program.to_account_info(),
-                    };
-                    let cpi_context = CpiContext::new(token_program.to_account_info(), cpi_accounts);
-                    associated_token::create_idempotent(cpi_context)?;
-
-                    // mint is checked here against the holder token account mint
-                    let cpi_accounts = Transfer {
-                        from: holder_token_account.to_account_info(),
-                        to: target_token_account_info.to_account_info(),
-                        authority: holder.to_account_info(),
-                    };
-                    let cpi_context = CpiContext::new(token_program.to_account_info(), cpi_accounts);
-                    token::transfer(cpi_context, token_check.amount)?;
+                        amount,
+                    });
                 }
             }
             MintPhaseTokenCheckMode::Burn => {
                 let mint_acocunt_info = next_account_info(remaining_accounts)?;
-                let cpi_accounts = Burn {
+                token_check_actions.push(TokenCheckAction::Burn {
+                    holder: holder.to_account_info(),
+                    holder_token_account: holder_token_account.to_account_info(),
                     mint: mint_acocunt_info.to_account_info(),
-                    from: holder_token_account.to_account_info(),
-                    authority: holder.to_account_info(),
+                    token_program: token_program.to_account_info(),
+                    amount,
+                });
+            }
+        }
+    }
+
+    Ok(MintValidation::Valid(token_check_actions))
+}
+
+pub fn execute_token_checks<'info>(token_check_actions: Vec<TokenCheckAction<'info>>, payer: AccountInfo<'info>, system_program: AccountInfo<'info>) -> Result<()> {
+    for token_check_action in token_check_actions {
+        match token_check_action {
+            TokenCheckAction::TransferSol { holder, transfer_target, amount } => {
+                invoke(&transfer(&holder.key(), &transfer_target.key(), amount), &[holder, transfer_target, system_program.to_account_info()])?;
+            }
+            TokenCheckAction::TransferToken {
+                holder,
+                holder_token_account,
+                transfer_target,
+                target_token_account,
+                target_mint,
+                token_program,
+                amount,
+            } => {
+                let cpi_accounts = associated_token::Create {
+                    mint: target_mint,
+                    payer: payer.to_account_info(),
+                    authority: transfer_target,
+                    associated_token: target_token_account.to_account_info(),
+                    system_program: system_program.to_account_info(),
+                    token_program: token_program.to_account_info(),
                 };
                 let cpi_context = CpiContext::new(token_program.to_account_info(), cpi_accounts);
-                token::burn(cpi_context, token_check.amount)?;
+                associated_token::create_idempotent(cpi_context)?;
+
+                // mint is checked here against the holder token account mint
+                let cpi_accounts = Transfer {
+                    from: holder_token_account,
+                    to: target_token_account,
+                    authority: holder,
+                };
+                let cpi_context = CpiContext::new(token_program, cpi_accounts);
+                token::transfer(cpi_context, amount)?;
+            }
+            TokenCheckAction::Burn {
+                holder,
+                holder_token_account,
+                mint,
+                token_program,
+                amount,
+            } => {
+                let cpi_accounts = Burn {
+                    mint,
+                    from: holder_token_account,
+                    authority: holder,
+                };
+                let cpi_context = CpiContext::new(token_program, cpi_accounts);
+                token::burn(cpi_context, amount)?;
             }
         }
     }
@@ -250,52 +472,243 @@ pub fn handle_token_checks<'info>(mint_phase: &MintPhase, remaining_accounts: &m
     Ok(())
 }
 
-pub fn handle_authorization_checks<'info>(
-    program_id: &Pubkey,
+// returns the updated authorization record to save once the mint is valid
+pub fn validate_authorization_checks<'info>(
     mint_config: &Account<'info, MintConfig>,
     mint_phase_ix: u8,
     mint_phase: &MintPhase,
     remaining_accounts: &mut Iter<AccountInfo<'info>>,
-) -> Result<()> {
+) -> Result<MintValidation<Option<Account<'info, MintPhaseAuthorization>>>> {
     if let Some(authorization) = &mint_phase.authorization {
         let user = next_account_info(remaining_accounts)?;
         let holder = next_account_info(remaining_accounts)?;
         if !holder.is_signer {
-            return Err(error!(ErrorCode::HolderNotSigner));
+            return Ok(MintValidation::Invalid(ErrorCode::HolderNotSigner));
         }
         if holder.key() != user.key() && holder.key() != mint_config.authority {
-            return Err(error!(ErrorCode::IncorrectAuthorizationHolder));
+            return Ok(MintValidation::Invalid(ErrorCode::IncorrectAuthorizationHolder));
         }
         let mint_phase_authorization_account_info = next_account_info(remaining_accounts)?;
         MintPhaseAuthorization::seeds(&mint_config.key(), mint_phase_ix, &user.key(), &mint_phase_authorization_account_info.key())?;
 
         if mint_phase_authorization_account_info.data_is_empty() {
             if authorization.mode == MintPhaseAuthorizationMode::DefaultDisallowed {
-                return Err(error!(ErrorCode::InvalidMintPhaseAuthorization));
+                return Ok(MintValidation::Invalid(ErrorCode::InvalidMintPhaseAuthorization));
             }
         } else {
             let mut mint_phase_authorization = Account::<MintPhaseAuthorization>::try_from(mint_phase_authorization_account_info)?;
             if &mint_phase_authorization.mint_config != &mint_config.key() || mint_phase_authorization.mint_phase_index != mint_phase_ix || mint_phase_authorization.user != user.key() {
-                return Err(error!(ErrorCode::InvalidMintPhaseAuthorization));
+                return Ok(MintValidation::Invalid(ErrorCode::InvalidMintPhaseAuthorization));
             }
 
             if let Some(remaining) = mint_phase_authorization.remaining {
                 if remaining == 0 {
-                    return Err(error!(ErrorCode::MintPhaseAuthorizationsUsed));
+                    return Ok(MintValidation::Invalid(ErrorCode::MintPhaseAuthorizationsUsed));
                 }
                 mint_phase_authorization.remaining = Some(remaining.saturating_sub(1));
             }
             mint_phase_authorization.count = mint_phase_authorization.count.saturating_add(1);
-            mint_phase_authorization.exit(program_id)?;
+            return Ok(MintValidation::Valid(Some(mint_phase_authorization)));
+        }
+    }
+
+    Ok(MintValidation::Valid(None))
+}
+
+pub fn handle_mint_cooldown<'info>(
+    program_id: &Pubkey,
+    mint_config: &Account<'info, MintConfig>,
+    mint_phase_ix: u8,
+    mint_phase: &MintPhase,
+    user: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    remaining_accounts: &mut Iter<AccountInfo<'info>>,
+) -> Result<()> {
+    if let Some(cooldown_seconds) = mint_phase.cooldown_seconds {
+        if !user.is_signer {
+            return Err(error!(ErrorCode::UserNotSigner));
+        }
+        let mint_cooldown_info = next_account_info(remaining_accounts)?;
+        let (mint_cooldown_seeds, bump) = MintCooldown::seeds(&mint_config.key(), mint_phase_ix, &user.key(), &mint_cooldown_info.key())?;
+        let timestamp = Clock::get()?.unix_timestamp;
+
+        // created on the first mint from the phase
+        if mint_cooldown_info.data_is_empty() {
+            create_pda_account(mint_cooldown_info, MINT_COOLDOWN_SIZE, program_id, &mint_cooldown_seeds, &payer, &system_program)?;
+            let mint_cooldown = &mut Account::<MintCooldown>::try_from_unchecked(mint_cooldown_info)?;
+            mint_cooldown.bump = bump;
+            mint_cooldown.mint_config = mint_config.key();
+            mint_cooldown.mint_phase_index = mint_phase_ix;
+            mint_cooldown.user = user.key();
+            // never minted
+            mint_cooldown.last_mint_seconds = i64::MIN;
+            mint_cooldown.exit(program_id)?;
+        }
+
+        let mut mint_cooldown = Account::<MintCooldown>::try_from(mint_cooldown_info)?;
+        if mint_cooldown.is_active(cooldown_seconds, timestamp) {
+            return Err(error!(ErrorCode::MintCooldownActive));
+        }
+        mint_cooldown.last_mint_seconds = timestamp;
+        mint_cooldown.exit(program_id)?;
+    }
+    Ok(())
+}
+
+pub fn handle_mint_counter<'info>(
+    program_id: &Pubkey,
+    mint_config: &Account<'info, MintConfig>,
+    mint_phase_ix: u8,
+    mint_phase: &MintPhase,
+    user: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    remaining_accounts: &mut Iter<AccountInfo<'info>>,
+) -> Result<()> {
+    if let Some(max_per_wallet) = mint_phase.max_per_wallet {
+        // the counter is keyed on the user so it must sign to stop the limit being spread over addresses
+        if !user.is_signer {
+            return Err(error!(ErrorCode::UserNotSigner));
+        }
+        let mint_counter_info = next_account_info(remaining_accounts)?;
+        let (mint_counter_seeds, bump) = MintCounter::seeds(&mint_config.key(), mint_phase_ix, &user.key(), &mint_counter_info.key())?;
+
+        // created on the first mint from the phase
+        if mint_counter_info.data_is_empty() {
+            create_pda_account(mint_counter_info, MINT_COUNTER_SIZE, program_id, &mint_counter_seeds, &payer, &system_program)?;
+            let mint_counter = &mut Account::<MintCounter>::try_from_unchecked(mint_counter_info)?;
+            mint_counter.bump = bump;
+            mint_counter.mint_config = mint_config.key();
+            mint_counter.mint_phase_index = mint_phase_ix;
+            mint_counter.user = user.key();
+            mint_counter.count = 0;
+            mint_counter.exit(program_id)?;
+        }
+
+        let mut mint_counter = Account::<MintCounter>::try_from(mint_counter_info)?;
+        if mint_counter.count >= max_per_wallet {
+            return Err(error!(ErrorCode::MintLimitReached));
+        }
+        mint_counter.count = mint_counter.count.checked_add(1).expect("Add error");
+        mint_counter.exit(program_id)?;
+    }
+    Ok(())
+}
+
+pub fn handle_mint_wallet_counter<'info>(
+    program_id: &Pubkey,
+    mint_config: &Account<'info, MintConfig>,
+    user: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    remaining_accounts: &mut Iter<AccountInfo<'info>>,
+) -> Result<()> {
+    if let Some(max_per_wallet_total) = mint_config.max_per_wallet_total {
+        if !user.is_signer {
+            return Err(error!(ErrorCode::UserNotSigner));
+        }
+        let mint_wallet_counter_info = next_account_info(remaining_accounts)?;
+        let (mint_wallet_counter_seeds, bump) = MintWalletCounter::seeds(&mint_config.key(), &user.key(), &mint_wallet_counter_info.key())?;
+
+        // created on the first mint from any phase
+        if mint_wallet_counter_info.data_is_empty() {
+            create_pda_account(mint_wallet_counter_info, MINT_WALLET_COUNTER_SIZE, program_id, &mint_wallet_counter_seeds, &payer, &system_program)?;
+            let mint_wallet_counter = &mut Account::<MintWalletCounter>::try_from_unchecked(mint_wallet_counter_info)?;
+            mint_wallet_counter.bump = bump;
+            mint_wallet_counter.mint_config = mint_config.key();
+            mint_wallet_counter.user = user.key();
+            mint_wallet_counter.count = 0;
+            mint_wallet_counter.exit(program_id)?;
+        }
+
+        let mut mint_wallet_counter = Account::<MintWalletCounter>::try_from(mint_wallet_counter_info)?;
+        if mint_wallet_counter.count >= max_per_wallet_total {
+            return Err(error!(ErrorCode::MintLimitReached));
         }
+        mint_wallet_counter.count = mint_wallet_counter.count.checked_add(1).expect("Add error");
+        mint_wallet_counter.exit(program_id)?;
+    }
+    Ok(())
+}
+
+pub fn handle_mint_commit<'info>(
+    mint_config: &mut Account<'info, MintConfig>,
+    mint_phase_ix: u8,
+    user: AccountInfo<'info>,
+    payer: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    remaining_accounts: &mut Iter<AccountInfo<'info>>,
+) -> Result<()> {
+    // the pending mint is keyed on the user so it must sign to stop commits being opened for other wallets
+    if !user.is_signer {
+        return Err(error!(ErrorCode::UserNotSigner));
+    }
+    let pending_mint_info = next_account_info(remaining_accounts)?;
+    let (pending_mint_seeds, bump) = PendingMint::seeds(&mint_config.key(), &user.key(), &pending_mint_info.key())?;
+    create_pda_account(pending_mint_info, PENDING_MINT_SIZE, &crate::ID, &pending_mint_seeds, &payer, &system_program)?;
+
+    let commit_slot = Clock::get()?.slot;
+    let pending_mint = &mut Account::<PendingMint>::try_from_unchecked(pending_mint_info)?;
+    pending_mint.bump = bump;
+    pending_mint.mint_config = mint_config.key();
+    pending_mint.user = user.key();
+    pending_mint.payer = payer.key();
+    pending_mint.mint_phase_index = mint_phase_ix;
+    pending_mint.commit_slot = commit_slot;
+    pending_mint.reveal_slot = commit_slot.checked_add(1).expect("Add error");
+    pending_mint.commit_hash = hashv(&[
+        mint_config.key().as_ref(),
+        user.key().as_ref(),
+        payer.key().as_ref(),
+        &commit_slot.to_le_bytes(),
+        &mint_config.count.to_le_bytes(),
+        &mint_config.pending_reveals.to_le_bytes(),
+    ])
+    .to_bytes();
+
+    // request randomness from the oracle signed by the pending mint
+    if let RandomnessSource::Vrf { oracle_program } = mint_config.output_mint_config.randomness_source {
+        let vrf_randomness_info = next_account_info(remaining_accounts)?;
+        let oracle_program_info = next_account_info(remaining_accounts)?;
+        if oracle_program_info.key() != oracle_program {
+            return Err(error!(ErrorCode::InvalidProgramId));
+        }
+        invoke_signed(
+            &Instruction {
+                program_id: oracle_program,
+                accounts: vec![
+                    AccountMeta::new(vrf_randomness_info.key(), false),
+                    AccountMeta::new_readonly(pending_mint_info.key(), true),
+                    AccountMeta::new(payer.key(), true),
+                    AccountMeta::new_readonly(system_program.key(), false),
+                ],
+                data: [VRF_REQUEST_RANDOMNESS_DISCRIMINATOR.as_ref(), pending_mint.commit_hash.as_ref()].concat(),
+            },
+            &[
+                oracle_program_info.to_account_info(),
+                vrf_randomness_info.to_account_info(),
+                pending_mint_info.to_account_info(),
+                payer.to_account_info(),
+                system_program.to_account_info(),
+            ],
+            &[&pending_mint_seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>()],
+        )?;
+        pending_mint.vrf_randomness = Some(vrf_randomness_info.key());
     }
+    pending_mint.exit(pending_mint_info.owner)?;
 
+    // reserve a token for the reveal
+    mint_config.pending_reveals = mint_config.pending_reveals.checked_add(1).expect("Add error");
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn handle_mint_nft<'info>(
     output_mint_entry: MintEntry,
     mint_config: &Account<'info, MintConfig>,
+    output_mint_config: &OutputMintConfig,
+    mint_phase_ix: u8,
     user: AccountInfo<'info>,
     payer: AccountInfo<'info>,
     system_program: AccountInfo<'info>,
@@ -331,17 +744,23 @@ pub fn handle_mint_nft<'info>(
             ],
             data: MetadataInstruction::Create(CreateArgs::V1 {
                 asset_data: AssetData {
-                    name: output_mint_entry.name.to_string(),
+                    name: match &output_mint_config.hidden_settings {
+                        Some(hidden_settings) => hidden_settings.name.to_string(),
+                        None => output_mint_entry.name.to_string(),
+                    },
                     symbol: output_mint_entry.symbol.to_string(),
-                    uri: output_mint_entry.uri.to_string(),
-                    seller_fee_basis_points: mint_config.output_mint_config.seller_fee_basis_points,
-                    creators: Some(output_mint_mpl_creators(&mint_config)),
+                    uri: match &output_mint_config.hidden_settings {
+                        Some(hidden_settings) => hidden_settings.uri.to_string(),
+                        None => output_mint_entry.uri.to_string(),
+                    },
+                    seller_fee_basis_points: output_mint_config.seller_fee_basis_points,
+                    creators: Some(output_mint_mpl_creators(mint_config, output_mint_config)),
                     primary_sale_happened: true,
                     is_mutable: true,
-                    token_standard: mint_config.output_mint_config.token_standard.mpl_token_standard(),
-                    collection: if mint_config.output_mint_config.collection.is_some() {
+                    token_standard: output_mint_config.token_standard.mpl_token_standard(),
+                    collection: if output_mint_config.collection.is_some() {
                         Some(Collection {
-                            key: mint_config.output_mint_config.collection.unwrap(),
+                            key: output_mint_config.collection.unwrap(),
                             verified: false,
                         })
                     } else {
@@ -349,8 +768,8 @@ pub fn handle_mint_nft<'info>(
                     },
                     uses: None,
                     collection_details: None,
-                    rule_set: if mint_config.output_mint_config.ruleset.is_some() {
-                        Some(mint_config.output_mint_config.ruleset.expect("Ruleset not found"))
+                    rule_set: if output_mint_config.ruleset.is_some() {
+                        Some(output_mint_config.ruleset.expect("Ruleset not found"))
                     } else {
                         None
                     },
@@ -421,7 +840,7 @@ pub fn handle_mint_nft<'info>(
     )?;
 
     // verify collection
-    if mint_config.output_mint_config.collection.is_some() {
+    if output_mint_config.collection.is_some() {
         let collection_mint_info = next_account_info(remaining_accounts)?;
         let collection_mint_metadata_info = next_account_info(remaining_accounts)?;
         let collection_mint_edition_info = next_account_info(remaining_accounts)?;
@@ -455,65 +874,39 @@ pub fn handle_mint_nft<'info>(
         )?;
     }
 
-    // update authority
-    invoke_signed(
-        &Instruction {
-            program_id: mpl_token_metadata::id(),
-            accounts: vec![
-                AccountMeta::new(mint_config.key(), true),
-                AccountMeta::new_readonly(token_metadata_program.key(), false),
-                AccountMeta::new_readonly(output_mint_user_token_account.key(), false),
-                AccountMeta::new_readonly(output_mint.key(), false),
-                AccountMeta::new(output_mint_metadata.key(), false),
-                AccountMeta::new(output_mint_edition.key(), false),
-                AccountMeta::new(payer.key(), true),
-                AccountMeta::new_readonly(system_program.key(), false),
-                AccountMeta::new_readonly(instructions.key(), false),
-                AccountMeta::new_readonly(authorization_rules_program.key(), false),
-                AccountMeta::new_readonly(output_mint_authorization_rules.key(), false),
-            ],
-            data: MetadataInstruction::Update(UpdateArgs::V1 {
-                new_update_authority: Some(mint_config.authority),
-                data: Some(Data {
-                    name: output_mint_entry.name.to_string(),
-                    symbol: output_mint_entry.symbol.to_string(),
-                    uri: output_mint_entry.uri.to_string(),
-                    seller_fee_basis_points: mint_config.output_mint_config.seller_fee_basis_points,
-                    creators: Some(output_mint_mpl_creators(&mint_config)),
-                }),
-                primary_sale_happened: Some(true),
-                is_mutable: Some(true),
-                collection_details: CollectionDetailsToggle::None,
-                collection: CollectionToggle::None,
-                uses: UsesToggle::None,
-                rule_set: if mint_config.output_mint_config.ruleset.is_some() {
-                    RuleSetToggle::Set(mint_config.output_mint_config.ruleset.expect("Ruleset not found"))
-                } else {
-                    RuleSetToggle::None
-                },
-                authorization_data: None,
-            })
-            .try_to_vec()
-            .unwrap(),
-        },
-        &[
-            mint_config.to_account_info(),
-            token_metadata_program.to_account_info(),
-            output_mint_user_token_account.to_account_info(),
-            output_mint.to_account_info(),
-            output_mint_metadata.to_account_info(),
-            output_mint_edition.to_account_info(),
-            payer.to_account_info(),
-            system_program.to_account_info(),
-            instructions.to_account_info(),
-            authorization_rules_program.to_account_info(),
-            output_mint_authorization_rules.to_account_info(),
-        ],
-        &[&mint_config_seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>()],
-    )?;
+    // hidden tokens keep the mint config as update authority until they are revealed
+    if output_mint_config.hidden_settings.is_some() {
+        let hidden_mint_info = next_account_info(remaining_accounts)?;
+        let (hidden_mint_seeds, bump) = HiddenMint::seeds(&mint_config.key(), &output_mint.key(), &hidden_mint_info.key())?;
+        create_pda_account(hidden_mint_info, HIDDEN_MINT_SIZE, &crate::ID, &hidden_mint_seeds, &payer, &system_program)?;
+        let hidden_mint = &mut Account::<HiddenMint>::try_from_unchecked(hidden_mint_info)?;
+        hidden_mint.bump = bump;
+        hidden_mint.mint_config = mint_config.key();
+        hidden_mint.mint = output_mint.key();
+        hidden_mint.payer = payer.key();
+        hidden_mint.mint_entry = output_mint_entry;
+        hidden_mint.mint_phase_index = mint_phase_ix;
+        hidden_mint.exit(hidden_mint_info.owner)?;
+    } else {
+        handle_update_output_mint_metadata(
+            &output_mint_entry,
+            mint_config,
+            output_mint_config,
+            output_mint_user_token_account,
+            output_mint,
+            output_mint_metadata,
+            output_mint_edition,
+            &payer,
+            &system_program,
+            instructions,
+            token_metadata_program,
+            authorization_rules_program,
+            output_mint_authorization_rules,
+        )?;
+    }
 
     // lock token
-    if mint_config.output_mint_config.release_authority.is_some() {
+    if let Some(release_authority) = output_mint_config.release_authority {
         let output_mint_pending_release_info = next_account_info(remaining_accounts)?;
         let (output_mint_pending_release_info_seeds, bump) = OutputMintPendingRelease::seeds(&mint_config.key(), &output_mint.key(), &output_mint_pending_release_info.key())?;
         invoke_signed(
@@ -532,6 +925,7 @@ pub fn handle_mint_nft<'info>(
         output_mint_pending_release.mint_config = mint_config.key();
         output_mint_pending_release.mint = output_mint.key();
         output_mint_pending_release.holder = user.key();
+        output_mint_pending_release.release_authority = release_authority;
         output_mint_pending_release.exit(output_mint_pending_release_info.owner)?;
 
         invoke_signed(
@@ -626,6 +1020,7 @@ pub fn handle_mint_nft<'info>(
 pub fn handle_mint_cnft<'info>(
     output_mint_entry: MintEntry,
     mint_config: &Account<'info, MintConfig>,
+    output_mint_config: &OutputMintConfig,
     user: AccountInfo<'info>,
     payer: AccountInfo<'info>,
     system_program: AccountInfo<'info>,
@@ -661,15 +1056,15 @@ pub fn handle_mint_cnft<'info>(
             name: output_mint_entry.name.to_string(),
             symbol: output_mint_entry.symbol.to_string(),
             uri: output_mint_entry.uri.to_string(),
-            seller_fee_basis_points: mint_config.output_mint_config.seller_fee_basis_points,
-            creators: output_mint_bg_creators(&mint_config),
+            seller_fee_basis_points: output_mint_config.seller_fee_basis_points,
+            creators: output_mint_bg_creators(mint_config, output_mint_config),
             primary_sale_happened: true,
             is_mutable: true,
             edition_nonce: None,
-            token_standard: Some(mint_config.output_mint_config.token_standard.bg_token_standard()?),
-            collection: if mint_config.output_mint_config.collection.is_some() {
+            token_standard: Some(output_mint_config.token_standard.bg_token_standard()?),
+            collection: if output_mint_config.collection.is_some() {
                 Some(mpl_bubblegum::state::metaplex_adapter::Collection {
-                    key: mint_config.output_mint_config.collection.unwrap(),
+                    key: output_mint_config.collection.unwrap(),
                     verified: false,
                 })
             } else {
@@ -682,3 +1077,80 @@ pub fn handle_mint_cnft<'info>(
 
     Ok(())
 }
+
+// set the entry data and hand the update authority to the mint config authority
+#[allow(clippy::too_many_arguments)]
+pub fn handle_update_output_mint_metadata<'info>(
+    output_mint_entry: &MintEntry,
+    mint_config: &Account<'info, MintConfig>,
+    output_mint_config: &OutputMintConfig,
+    output_mint_token_account: &AccountInfo<'info>,
+    output_mint: &AccountInfo<'info>,
+    output_mint_metadata: &AccountInfo<'info>,
+    output_mint_edition: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    instructions: &AccountInfo<'info>,
+    token_metadata_program: &AccountInfo<'info>,
+    authorization_rules_program: &AccountInfo<'info>,
+    output_mint_authorization_rules: &AccountInfo<'info>,
+) -> Result<()> {
+    let mint_config_seeds = MintConfig::seeds(&mint_config.name, &mint_config.key())?;
+    invoke_signed(
+        &Instruction {
+            program_id: mpl_token_metadata::id(),
+            accounts: vec![
+                AccountMeta::new(mint_config.key(), true),
+                AccountMeta::new_readonly(token_metadata_program.key(), false),
+                AccountMeta::new_readonly(output_mint_token_account.key(), false),
+                AccountMeta::new_readonly(output_mint.key(), false),
+                AccountMeta::new(output_mint_metadata.key(), false),
+                AccountMeta::new(output_mint_edition.key(), false),
+                AccountMeta::new(payer.key(), true),
+                AccountMeta::new_readonly(system_program.key(), false),
+                AccountMeta::new_readonly(instructions.key(), false),
+                AccountMeta::new_readonly(authorization_rules_program.key(), false),
+                AccountMeta::new_readonly(output_mint_authorization_rules.key(), false),
+            ],
+            data: MetadataInstruction::Update(UpdateArgs::V1 {
+                new_update_authority: Some(mint_config.authority),
+                data: Some(Data {
+                    name: output_mint_entry.name.to_string(),
+                    symbol: output_mint_entry.symbol.to_string(),
+                    uri: output_mint_entry.uri.to_string(),
+                    seller_fee_basis_points: output_mint_config.seller_fee_basis_points,
+                    creators: Some(output_mint_mpl_creators(mint_config, output_mint_config)),
+                }),
+                primary_sale_happened: Some(true),
+                is_mutable: Some(true),
+                collection_details: CollectionDetailsToggle::None,
+                collection: CollectionToggle::None,
+                uses: UsesToggle::None,
+                rule_set: if output_mint_config.ruleset.is_some() {
+                    RuleSetToggle::Set(output_mint_config.ruleset.expect("Ruleset not found"))
+                } else {
+                    RuleSetToggle::None
+                },
+                authorization_data: None,
+            })
+            .try_to_vec()
+            .unwrap(),
+        },
+        &[
+            mint_config.to_account_info(),
+            token_metadata_program.to_account_info(),
+            output_mint_token_account.to_account_info(),
+            output_mint.to_account_info(),
+            output_mint_metadata.to_account_info(),
+            output_mint_edition.to_account_info(),
+            payer.to_account_info(),
+            system_program.to_account_info(),
+            instructions.to_account_info(),
+            authorization_rules_program.to_account_info(),
+            output_mint_authorization_rules.to_account_info(),
+        ],
+        &[&mint_config_seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>()],
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use crate::errors::ErrorCode;
+use crate::instructions::mint::handle_bot_tax;
+use crate::instructions::mint::handle_mint;
+use crate::instructions::mint::validate_mint;
+use crate::instructions::mint::MintCtx;
+use crate::state::MerkleMintEntry;
+use crate::state::MintCooldown;
+use crate::state::MintCounter;
+use crate::state::MintPhaseSelection;
+use crate::state::MintValidation;
+use crate::state::MintWalletCounter;
+use anchor_lang::prelude::*;
+use solana_program::program::set_return_data;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MintAutoIx {
+    // entry and proof for the next entry when using merkle storage
+    merkle_mint_entry: Option<MerkleMintEntry>,
+    // candidate phases in phase order, each followed in the remaining accounts by the accounts `mint` takes for it
+    phase_accounts: Vec<MintAutoPhaseAccounts>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MintAutoPhaseAccounts {
+    pub mint_phase_index: u8,
+    pub accounts_length: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MintAutoResult {
+    pub mint_phase_index: u8,
+    // false when the bot tax was charged instead of minting
+    pub minted: bool,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, MintCtx<'info>>, ix: MintAutoIx) -> Result<()> {
+    // split the remaining accounts into one group per candidate phase
+    // NOTE: accounts shared between groups are deduplicated in the transaction so repeating them is cheap
+    let mut candidates = Vec::with_capacity(ix.phase_accounts.len());
+    let mut start: usize = 0;
+    for (i, phase_accounts) in ix.phase_accounts.iter().enumerate() {
+        if i > 0 && phase_accounts.mint_phase_index <= ix.phase_accounts[i - 1].mint_phase_index {
+            return Err(error!(ErrorCode::InvalidPhase));
+        }
+        let end = start.checked_add(usize::from(phase_accounts.accounts_length)).expect("Add error");
+        let accounts = ctx.remaining_accounts.get(start..end).ok_or(ProgramError::NotEnoughAccountKeys)?;
+        candidates.push((phase_accounts.mint_phase_index, accounts));
+        start = end;
+    }
+
+    // only the group of each candidate is read to check the user qualifies for it
+    let mut qualified = Vec::new();
+    let mut taxed_phase = None;
+    for (mint_phase_ix, accounts) in &candidates {
+        match qualifies(ctx.accounts, *mint_phase_ix, accounts)? {
+            MintValidation::Valid(true) => qualified.push((*mint_phase_ix, *accounts)),
+            MintValidation::Valid(false) => {}
+            MintValidation::Invalid(error_code) => {
+                taxed_phase.get_or_insert((*mint_phase_ix, error_code));
+            }
+        }
+    }
+    let mint_config = &ctx.accounts.mint_config;
+    let selected_phase = match mint_config.phase_selection {
+        MintPhaseSelection::First => qualified.first(),
+        MintPhaseSelection::Cheapest => {
+            let timestamp = Clock::get()?.unix_timestamp;
+            let mint_count = mint_config.count.saturating_add(mint_config.pending_reveals);
+            qualified
+                .iter()
+                .min_by_key(|(mint_phase_ix, _)| mint_config.mint_phases[usize::from(*mint_phase_ix)].transfer_amount(timestamp, mint_count))
+        }
+    }
+    .copied();
+    let (mint_phase_ix, accounts) = match (selected_phase, taxed_phase) {
+        (Some(selected_phase), _) => selected_phase,
+        // charge the bot tax for the first failed phase check the same way `mint` would so bots cannot avoid it here
+        (None, Some((mint_phase_ix, error_code))) => {
+            if mint_config.paused {
+                return Err(error!(ErrorCode::MintPaused));
+            }
+            if mint_config.available_tokens() == 0 {
+                return Err(error!(ErrorCode::NotTokensRemaining));
+            }
+            handle_bot_tax(ctx.accounts, error_code)?;
+            set_return_data(
+                &MintAutoResult {
+                    mint_phase_index: mint_phase_ix,
+                    minted: false,
+                }
+                .try_to_vec()?,
+            );
+            return Ok(());
+        }
+        (None, None) => return Err(error!(ErrorCode::NoActivePhase)),
+    };
+
+    // mint with the accounts of the chosen phase only
+    let minted = handle_mint(ctx.program_id, ctx.accounts, mint_phase_ix, ix.merkle_mint_entry, &mut accounts.iter())?;
+
+    // set last so the return data of the cpis is overwritten
+    set_return_data(
+        &MintAutoResult {
+            mint_phase_index: mint_phase_ix,
+            minted,
+        }
+        .try_to_vec()?,
+    );
+    Ok(())
+}
+
+// checks the phase, token checks, authorization and per user limits without changing any state
+// returns the taxable error when a check `mint` taxes fails and false when a per user limit is reached
+// NOTE: accounts are read in the same order `mint` reads them
+pub fn qualifies<'info>(accounts: &MintCtx<'info>, mint_phase_ix: u8, phase_accounts: &[AccountInfo<'info>]) -> Result<MintValidation<bool>> {
+    let mint_config = &accounts.mint_config;
+    let remaining_accounts = &mut phase_accounts.iter();
+    let is_authority = accounts.payer.key() == mint_config.authority;
+    if let MintValidation::Invalid(error_code) = validate_mint(mint_config, mint_phase_ix, is_authority, remaining_accounts)? {
+        return Ok(MintValidation::Invalid(error_code));
+    }
+    let mint_phase = &mint_config.mint_phases[usize::from(mint_phase_ix)];
+    let user = accounts.user.key();
+
+    if let Some(cooldown_seconds) = mint_phase.cooldown_seconds {
+        let mint_cooldown_info = next_account_info(remaining_accounts)?;
+        MintCooldown::seeds(&mint_config.key(), mint_phase_ix, &user, &mint_cooldown_info.key())?;
+        if !mint_cooldown_info.data_is_empty() && Account::<MintCooldown>::try_from(mint_cooldown_info)?.is_active(cooldown_seconds, Clock::get()?.unix_timestamp) {
+            return Ok(MintValidation::Valid(false));
+        }
+    }
+    if let Some(max_per_wallet) = mint_phase.max_per_wallet {
+        let mint_counter_info = next_account_info(remaining_accounts)?;
+        MintCounter::seeds(&mint_config.key(), mint_phase_ix, &user, &mint_counter_info.key())?;
+        if !mint_counter_info.data_is_empty() && Account::<MintCounter>::try_from(mint_counter_info)?.count >= max_per_wallet {
+            return Ok(MintValidation::Valid(false));
+        }
+    }
+    if let Some(max_per_wallet_total) = mint_config.max_per_wallet_total {
+        let mint_wallet_counter_info = next_account_info(remaining_accounts)?;
+        MintWalletCounter::seeds(&mint_config.key(), &user, &mint_wallet_counter_info.key())?;
+        if !mint_wallet_counter_info.data_is_empty() && Account::<MintWalletCounter>::try_from(mint_wallet_counter_info)?.count >= max_per_wallet_total {
+            return Ok(MintValidation::Valid(false));
+        }
+    }
+    Ok(MintValidation::Valid(true))
+}
@@ -1,8 +1,44 @@
 pub mod mint;
 pub use mint::*;
 
+pub mod mint_auto;
+pub use mint_auto::*;
+
 pub mod set_mint_entry;
 pub use set_mint_entry::*;
 
+pub mod set_mint_entries;
+pub use set_mint_entries::*;
+
+pub mod set_mint_entry_template;
+pub use set_mint_entry_template::*;
+
+pub mod set_mint_entry_merkle_root;
+pub use set_mint_entry_merkle_root::*;
+
+pub mod remove_mint_entries;
+pub use remove_mint_entries::*;
+
+pub mod truncate_supply;
+pub use truncate_supply::*;
+
+pub mod init_mint_entry_page;
+pub use init_mint_entry_page::*;
+
+pub mod close_mint_entry_page;
+pub use close_mint_entry_page::*;
+
 pub mod release_output_mint;
 pub use release_output_mint::*;
+
+pub mod reveal_mint;
+pub use reveal_mint::*;
+
+pub mod expire_pending_mint;
+pub use expire_pending_mint::*;
+
+pub mod vrf_callback;
+pub use vrf_callback::*;
+
+pub mod reveal_output_mint;
+pub use reveal_output_mint::*;
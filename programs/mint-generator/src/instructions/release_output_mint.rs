@@ -1,5 +1,6 @@
 use crate::errors::ErrorCode;
 use crate::state::*;
+use crate::utils::close_account;
 use anchor_lang::prelude::*;
 use mpl_token_metadata::instruction::MetadataInstruction;
 use mpl_token_metadata::instruction::UnlockArgs;
@@ -10,8 +11,9 @@ use solana_program::program::invoke_signed;
 pub struct ReleaseOutputMintCtx<'info> {
     #[account(mut)]
     mint_config: Box<Account<'info, MintConfig>>,
-    #[account(mut, close = collector, constraint = output_mint_pending_release.mint_config == mint_config.key() && output_mint_pending_release.mint == output_mint.key() @ ErrorCode::InvalidOutputMintsPendingRelease)]
-    output_mint_pending_release: Box<Account<'info, OutputMintPendingRelease>>,
+    /// CHECK: Loaded in handler to support pending releases created before the release authority was stored
+    #[account(mut)]
+    output_mint_pending_release: UncheckedAccount<'info>,
     /// CHECK: Checked in CPI
     #[account(mut)]
     user: UncheckedAccount<'info>,
@@ -33,7 +35,7 @@ pub struct ReleaseOutputMintCtx<'info> {
 
     #[account(mut)]
     payer: Signer<'info>,
-    #[account(mut, constraint = mint_config.output_mint_config.release_authority.is_none() || mint_config.output_mint_config.release_authority.unwrap() == release_authority.key() @ ErrorCode::InvalidAuthority )]
+    #[account(mut)]
     release_authority: Signer<'info>,
     /// CHECK: Checked in CPI
     #[account(mut)]
@@ -51,9 +53,16 @@ pub struct ReleaseOutputMintCtx<'info> {
 }
 
 pub fn handler(ctx: Context<ReleaseOutputMintCtx>) -> Result<()> {
+    let (output_mint_pending_release, release_authority) = OutputMintPendingRelease::load(&ctx.accounts.output_mint_pending_release, &ctx.accounts.mint_config)?;
+    if output_mint_pending_release.mint_config != ctx.accounts.mint_config.key() || output_mint_pending_release.mint != ctx.accounts.output_mint.key() {
+        return Err(error!(ErrorCode::InvalidOutputMintsPendingRelease));
+    }
+    if release_authority.is_some() && release_authority.unwrap() != ctx.accounts.release_authority.key() {
+        return Err(error!(ErrorCode::InvalidAuthority));
+    }
     let (output_mint_pending_release_seeds, _) = OutputMintPendingRelease::seeds(
-        &ctx.accounts.output_mint_pending_release.mint_config,
-        &ctx.accounts.output_mint_pending_release.mint,
+        &output_mint_pending_release.mint_config,
+        &output_mint_pending_release.mint,
         &ctx.accounts.output_mint_pending_release.key(),
     )?;
     invoke_signed(
@@ -95,5 +104,5 @@ pub fn handler(ctx: Context<ReleaseOutputMintCtx>) -> Result<()> {
         &[&output_mint_pending_release_seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>()],
     )?;
 
-    Ok(())
+    close_account(&ctx.accounts.output_mint_pending_release, &ctx.accounts.collector)
 }
@@ -0,0 +1,28 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RemoveMintEntriesIx {
+    start_index: u64,
+    length: u64,
+}
+
+#[derive(Accounts)]
+pub struct RemoveMintEntriesCtx<'info> {
+    #[account(mut, constraint = mint_config.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    mint_config: Box<Account<'info, MintConfig>>,
+    #[account(mut)]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<RemoveMintEntriesCtx>, ix: RemoveMintEntriesIx) -> Result<()> {
+    remove_mint_entries(
+        &mut ctx.accounts.mint_config,
+        ix.start_index,
+        ix.start_index.checked_add(ix.length).expect("Add error"),
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )
+}
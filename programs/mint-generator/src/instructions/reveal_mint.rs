@@ -0,0 +1,101 @@
+use crate::errors::ErrorCode;
+use crate::instructions::mint::handle_mint_cnft;
+use crate::instructions::mint::handle_mint_nft;
+use crate::state::get_random_output_mint;
+use crate::state::MerkleMintEntry;
+use crate::state::MintConfig;
+use crate::state::PendingMint;
+use crate::utils::commit_reveal_random_number;
+use crate::utils::recent_slothash;
+use crate::utils::resize_account;
+use anchor_lang::prelude::*;
+use solana_program::sysvar;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RevealMintIx {
+    // entry and proof for the picked entry when using merkle storage
+    merkle_mint_entry: Option<MerkleMintEntry>,
+}
+
+#[derive(Accounts)]
+pub struct RevealMintCtx<'info> {
+    #[account(mut)]
+    mint_config: Box<Account<'info, MintConfig>>,
+    #[account(mut, close = payer, constraint = pending_mint.mint_config == mint_config.key() && pending_mint.user == user.key() @ ErrorCode::InvalidPendingMint)]
+    pending_mint: Box<Account<'info, PendingMint>>,
+    /// CHECK: Checked against pending mint
+    user: UncheckedAccount<'info>,
+    #[account(mut, constraint = pending_mint.payer == payer.key() @ ErrorCode::InvalidPendingMint)]
+    payer: Signer<'info>,
+    /// CHECK: Address is checked
+    #[account(mut, address = mint_config.authority @ ErrorCode::InvalidAuthority)]
+    collector: UncheckedAccount<'info>,
+    /// CHECK: Address is checked
+    #[account(address = sysvar::slot_hashes::id())]
+    recent_slothashes: UncheckedAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, RevealMintCtx<'info>>, ix: RevealMintIx) -> Result<()> {
+    // commits not revealed in their window are forfeited with `expire_pending_mint` so the reveal cannot be timed or skipped for a better pick
+    if ctx.accounts.pending_mint.is_expired(Clock::get()?.slot) {
+        return Err(error!(ErrorCode::PendingMintExpired));
+    }
+    let remaining_accounts = &mut ctx.remaining_accounts.iter();
+    let random_number = match ctx.accounts.pending_mint.vrf_randomness {
+        // the oracle stored the randomness with `vrf_callback`
+        Some(_) => {
+            let randomness = ctx.accounts.pending_mint.randomness.ok_or(ErrorCode::VrfRandomnessNotFulfilled)?;
+            ctx.accounts.mint_config.pending_reveals = ctx.accounts.mint_config.pending_reveals.saturating_sub(1);
+            commit_reveal_random_number(&randomness, &ctx.accounts.pending_mint.commit_hash)
+        }
+        None => {
+            let commit_slot = ctx.accounts.pending_mint.commit_slot;
+            if Clock::get()?.slot <= commit_slot {
+                return Err(error!(ErrorCode::RevealTooEarly));
+            }
+            ctx.accounts.mint_config.pending_reveals = ctx.accounts.mint_config.pending_reveals.saturating_sub(1);
+            let slothash = recent_slothash(&ctx.accounts.recent_slothashes, commit_slot)?.ok_or(ErrorCode::PendingMintExpired)?;
+            commit_reveal_random_number(&slothash, &ctx.accounts.pending_mint.commit_hash)
+        }
+    };
+
+    // get mint entry
+    let output_mint_entry = get_random_output_mint(&mut ctx.accounts.mint_config, random_number, ix.merkle_mint_entry, remaining_accounts)?;
+
+    // mint
+    let mint_phase_ix = ctx.accounts.pending_mint.mint_phase_index;
+    let output_mint_config = ctx.accounts.mint_config.phase_output_mint_config(mint_phase_ix);
+    if output_mint_config.merkle_tree.is_some() {
+        handle_mint_cnft(
+            output_mint_entry,
+            &ctx.accounts.mint_config,
+            &output_mint_config,
+            ctx.accounts.user.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            remaining_accounts,
+        )?;
+    } else {
+        handle_mint_nft(
+            output_mint_entry,
+            &ctx.accounts.mint_config,
+            &output_mint_config,
+            mint_phase_ix,
+            ctx.accounts.user.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            remaining_accounts,
+        )?;
+    }
+
+    // resize mint config last
+    resize_account(
+        &mut ctx.accounts.mint_config.to_account_info(),
+        ctx.accounts.mint_config.account_size(),
+        &ctx.accounts.collector,
+        &ctx.accounts.system_program,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::errors::ErrorCode;
+use crate::instructions::mint::handle_update_output_mint_metadata;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RevealOutputMintCtx<'info> {
+    // passed writable as the update authority in the metadata update
+    #[account(mut)]
+    mint_config: Box<Account<'info, MintConfig>>,
+    #[account(mut, close = payer, constraint = hidden_mint.mint_config == mint_config.key() && hidden_mint.mint == output_mint.key() @ ErrorCode::InvalidHiddenMint)]
+    hidden_mint: Box<Account<'info, HiddenMint>>,
+    /// CHECK: Checked in CPI
+    output_mint: UncheckedAccount<'info>,
+    /// CHECK: Checked in CPI
+    #[account(mut)]
+    output_mint_metadata: UncheckedAccount<'info>,
+    /// CHECK: Checked in CPI
+    #[account(mut)]
+    output_mint_edition: UncheckedAccount<'info>,
+    /// CHECK: Checked in CPI
+    output_mint_authorization_rules: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    reveal_authority: Signer<'info>,
+    /// CHECK: Address is checked
+    #[account(mut, address = hidden_mint.payer @ ErrorCode::InvalidHiddenMint)]
+    payer: UncheckedAccount<'info>,
+
+    system_program: Program<'info, System>,
+    /// CHECK: Checked in CPI
+    instructions: UncheckedAccount<'info>,
+    /// CHECK: Checked in CPI
+    authorization_rules_program: UncheckedAccount<'info>,
+    /// CHECK: Checked in CPI
+    token_metadata_program: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<RevealOutputMintCtx>) -> Result<()> {
+    if let Some(hidden_settings) = &ctx.accounts.mint_config.output_mint_config.hidden_settings {
+        if hidden_settings.reveal_authority.is_some() && hidden_settings.reveal_authority.unwrap() != ctx.accounts.reveal_authority.key() {
+            return Err(error!(ErrorCode::InvalidAuthority));
+        }
+    }
+
+    // token account is optional for the update authority so the token metadata program is passed in its place
+    handle_update_output_mint_metadata(
+        &ctx.accounts.hidden_mint.mint_entry,
+        &ctx.accounts.mint_config,
+        &ctx.accounts.mint_config.phase_output_mint_config(ctx.accounts.hidden_mint.mint_phase_index),
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        &ctx.accounts.output_mint.to_account_info(),
+        &ctx.accounts.output_mint_metadata.to_account_info(),
+        &ctx.accounts.output_mint_edition.to_account_info(),
+        &ctx.accounts.reveal_authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &ctx.accounts.instructions.to_account_info(),
+        &ctx.accounts.token_metadata_program.to_account_info(),
+        &ctx.accounts.authorization_rules_program.to_account_info(),
+        &ctx.accounts.output_mint_authorization_rules.to_account_info(),
+    )
+}
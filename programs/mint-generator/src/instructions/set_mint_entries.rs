@@ -0,0 +1,42 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetMintEntriesIx {
+    start_index: u64,
+    entries: Vec<MintEntryIx>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct MintEntryIx {
+    name: String,
+    symbol: String,
+    uri: String,
+    weight: Option<u16>,
+}
+
+#[derive(Accounts)]
+pub struct SetMintEntriesCtx<'info> {
+    #[account(mut, constraint = mint_config.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    mint_config: Box<Account<'info, MintConfig>>,
+    authority: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, SetMintEntriesCtx<'info>>, ix: SetMintEntriesIx) -> Result<()> {
+    let mut new_mint_entries = Vec::with_capacity(ix.entries.len());
+    for entry in ix.entries {
+        new_mint_entries.push(new_mint_entry(entry.name, entry.symbol, entry.uri, entry.weight)?);
+    }
+    set_mint_entries(
+        &mut ctx.accounts.mint_config,
+        ix.start_index,
+        new_mint_entries,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &mut ctx.remaining_accounts.iter(),
+    )
+}
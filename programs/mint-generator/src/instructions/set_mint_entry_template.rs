@@ -0,0 +1,94 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use std::cmp;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetMintEntryTemplateIx {
+    template: MintEntryTemplate,
+    supply: u64,
+}
+
+#[derive(Accounts)]
+#[instruction(ix: SetMintEntryTemplateIx)]
+pub struct SetMintEntryTemplateCtx<'info> {
+    #[account(mut, constraint = mint_config.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    mint_config: Box<Account<'info, MintConfig>>,
+    authority: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetMintEntryTemplateCtx>, ix: SetMintEntryTemplateIx) -> Result<()> {
+    let mint_config = &ctx.accounts.mint_config;
+    if mint_config.count > 0 || mint_config.pending_reveals > 0 {
+        return Err(error!(ErrorCode::MintingAlreadyStarted));
+    }
+    if mint_config.provenance_hash.is_some() {
+        return Err(error!(ErrorCode::ProvenanceHashSet));
+    }
+    // switching would drop entries that were already set
+    if (mint_config.entry_storage == MintEntryStorage::Inline && mint_config.supply > 0) || matches!(mint_config.entry_storage, MintEntryStorage::Paged { .. }) {
+        return Err(error!(ErrorCode::InvalidMintEntryStorage));
+    }
+    ix.template.check_valid(ix.supply)?;
+
+    let mut new_mint_config = MintConfig {
+        bump: mint_config.bump,
+        authority: mint_config.authority,
+        name: mint_config.name.clone(),
+        supply: 0,
+        count: mint_config.count,
+        output_mint_config: mint_config.output_mint_config.clone(),
+        mint_phases: mint_config.mint_phases.clone(),
+        metadata: mint_config.metadata.clone(),
+        pending_reveals: mint_config.pending_reveals,
+        total_weight: 0,
+        provenance_hash: mint_config.provenance_hash,
+        entry_storage: MintEntryStorage::Template { template: ix.template },
+        max_per_wallet_total: mint_config.max_per_wallet_total,
+        paused: mint_config.paused,
+        pause_authority: mint_config.pause_authority,
+        phase_selection: mint_config.phase_selection.clone(),
+        bot_tax_lamports: mint_config.bot_tax_lamports,
+        weight_block_sums: mint_config.weight_block_sums.clone(),
+    };
+    new_mint_config.check_valid()?;
+
+    // the account can only grow by `MAX_PERMITTED_DATA_INCREASE` per instruction so large supplies are set by calling again until the full supply is set
+    // NOTE: every call resets the index table so only remove entries once the full supply is set
+    let max_supply = mint_config
+        .to_account_info()
+        .data_len()
+        .checked_add(MAX_PERMITTED_DATA_INCREASE)
+        .expect("Add error")
+        .saturating_sub(new_mint_config.account_size())
+        / MINT_ENTRY_TEMPLATE_INDEX_SIZE;
+    let supply = cmp::min(ix.supply, max_supply as u64);
+    msg!("Template supply {} / {}", supply, ix.supply);
+    new_mint_config.supply = supply;
+    new_mint_config.total_weight = supply;
+    let new_data_length = new_mint_config.account_size();
+
+    // resize
+    resize_account(
+        &ctx.accounts.mint_config.to_account_info(),
+        new_data_length,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    // every entry starts at its own index
+    let mint_entries_length = (supply as usize).checked_mul(MINT_ENTRY_TEMPLATE_INDEX_SIZE).expect("Mul error");
+    let mint_config_account_info = ctx.accounts.mint_config.to_account_info();
+    let mut mint_config_data = mint_config_account_info.data.borrow_mut();
+    mint_config_data[new_data_length.checked_sub(mint_entries_length).expect("Sub error")..new_data_length].fill(0);
+
+    // save config
+    ctx.accounts.mint_config.set_inner(new_mint_config);
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TruncateSupplyIx {
+    supply: u64,
+}
+
+#[derive(Accounts)]
+pub struct TruncateSupplyCtx<'info> {
+    #[account(mut, constraint = mint_config.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    mint_config: Box<Account<'info, MintConfig>>,
+    #[account(mut)]
+    authority: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<TruncateSupplyCtx>, ix: TruncateSupplyIx) -> Result<()> {
+    let supply = ctx.accounts.mint_config.supply;
+    remove_mint_entries(
+        &mut ctx.accounts.mint_config,
+        ix.supply,
+        supply,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )
+}
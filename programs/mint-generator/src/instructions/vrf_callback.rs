@@ -0,0 +1,46 @@
+use crate::errors::ErrorCode;
+use crate::state::MintConfig;
+use crate::state::PendingMint;
+use crate::state::RandomnessSource;
+use crate::state::VrfRandomness;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct VrfCallbackCtx<'info> {
+    // only the oracle can sign for its randomness account
+    vrf_randomness: Signer<'info>,
+    #[account(mut, constraint = pending_mint.mint_config == mint_config.key() @ ErrorCode::InvalidPendingMint)]
+    pending_mint: Box<Account<'info, PendingMint>>,
+    mint_config: Box<Account<'info, MintConfig>>,
+}
+
+pub fn handler(ctx: Context<VrfCallbackCtx>) -> Result<()> {
+    let oracle_program = match ctx.accounts.mint_config.output_mint_config.randomness_source {
+        RandomnessSource::Vrf { oracle_program } => oracle_program,
+        _ => return Err(error!(ErrorCode::InvalidVrfRandomness)),
+    };
+    let pending_mint = &mut ctx.accounts.pending_mint;
+    if pending_mint.vrf_randomness != Some(ctx.accounts.vrf_randomness.key()) {
+        return Err(error!(ErrorCode::InvalidVrfRandomness));
+    }
+    // the randomness is fixed on the first callback so the oracle cannot re-roll it
+    if pending_mint.randomness.is_some() {
+        return Err(error!(ErrorCode::PendingMintAlreadyFulfilled));
+    }
+
+    let vrf_randomness = VrfRandomness::load(&ctx.accounts.vrf_randomness, &oracle_program)?;
+    if vrf_randomness.requester != pending_mint.key() || vrf_randomness.seed != pending_mint.commit_hash {
+        return Err(error!(ErrorCode::InvalidVrfRandomness));
+    }
+    if !vrf_randomness.fulfilled {
+        return Err(error!(ErrorCode::VrfRandomnessNotFulfilled));
+    }
+    // late randomness cannot reopen a commit that has already expired
+    let slot = Clock::get()?.slot;
+    if pending_mint.is_expired(slot) {
+        return Err(error!(ErrorCode::PendingMintExpired));
+    }
+    pending_mint.randomness = Some(vrf_randomness.randomness);
+    pending_mint.reveal_slot = slot;
+    Ok(())
+}
@@ -2,13 +2,16 @@ use anchor_lang::prelude::*;
 use authorization::*;
 use instructions::*;
 use mint_config::*;
+use views::*;
 
 pub mod authorization;
+pub mod conditions;
 pub mod errors;
 pub mod instructions;
 pub mod mint_config;
 pub mod state;
 pub mod utils;
+pub mod views;
 
 declare_id!("mintjBhypUqvbKvCePPsQN55AYBY3DwFWpuR5PDURdH");
 
@@ -28,10 +31,30 @@ pub mod mint_generator {
         set_mint_config_metadata::handler(ctx, ix)
     }
 
+    pub fn migrate_mint_config(ctx: Context<MigrateMintConfigCtx>) -> Result<()> {
+        migrate_mint_config::handler(ctx)
+    }
+
     pub fn close_mint_config(ctx: Context<CloseMintConfigCtx>) -> Result<()> {
         close_mint_config::handler(ctx)
     }
 
+    pub fn set_mint_config_provenance_hash(ctx: Context<SetMintConfigProvenanceHashCtx>, ix: SetMintConfigProvenanceHashIx) -> Result<()> {
+        set_mint_config_provenance_hash::handler(ctx, ix)
+    }
+
+    pub fn verify_mint_config_provenance_hash<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, VerifyMintConfigProvenanceHashCtx<'info>>) -> Result<()> {
+        verify_mint_config_provenance_hash::handler(ctx)
+    }
+
+    pub fn pause_mint(ctx: Context<PauseMintCtx>) -> Result<()> {
+        pause_mint::handler(ctx)
+    }
+
+    pub fn resume_mint(ctx: Context<ResumeMintCtx>) -> Result<()> {
+        resume_mint::handler(ctx)
+    }
+
     pub fn set_mint_phase_authorization(ctx: Context<SetMintPhaseAuthorizationCtx>, ix: SetMintPhaseAuthorizationIx) -> Result<()> {
         set_mint_phase_authorization::handler(ctx, ix)
     }
@@ -40,15 +63,75 @@ pub mod mint_generator {
         close_mint_phase_authorization::handler(ctx)
     }
 
-    pub fn set_mint_entry(ctx: Context<SetMintEntryCtx>, ix: SetMintEntryIx) -> Result<()> {
+    pub fn set_mint_entry<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, SetMintEntryCtx<'info>>, ix: SetMintEntryIx) -> Result<()> {
         set_mint_entry::handler(ctx, ix)
     }
 
+    pub fn set_mint_entries<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, SetMintEntriesCtx<'info>>, ix: SetMintEntriesIx) -> Result<()> {
+        set_mint_entries::handler(ctx, ix)
+    }
+
+    pub fn set_mint_entry_template(ctx: Context<SetMintEntryTemplateCtx>, ix: SetMintEntryTemplateIx) -> Result<()> {
+        set_mint_entry_template::handler(ctx, ix)
+    }
+
+    pub fn remove_mint_entries(ctx: Context<RemoveMintEntriesCtx>, ix: RemoveMintEntriesIx) -> Result<()> {
+        remove_mint_entries::handler(ctx, ix)
+    }
+
+    pub fn truncate_supply(ctx: Context<TruncateSupplyCtx>, ix: TruncateSupplyIx) -> Result<()> {
+        truncate_supply::handler(ctx, ix)
+    }
+
+    pub fn set_mint_entry_merkle_root(ctx: Context<SetMintEntryMerkleRootCtx>, ix: SetMintEntryMerkleRootIx) -> Result<()> {
+        set_mint_entry_merkle_root::handler(ctx, ix)
+    }
+
+    pub fn init_mint_entry_page(ctx: Context<InitMintEntryPageCtx>, ix: InitMintEntryPageIx) -> Result<()> {
+        init_mint_entry_page::handler(ctx, ix)
+    }
+
+    pub fn close_mint_entry_page(ctx: Context<CloseMintEntryPageCtx>) -> Result<()> {
+        close_mint_entry_page::handler(ctx)
+    }
+
     pub fn mint<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, MintCtx<'info>>, ix: MintIx) -> Result<()> {
         mint::handler(ctx, ix)
     }
 
+    pub fn mint_auto<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, MintCtx<'info>>, ix: MintAutoIx) -> Result<()> {
+        mint_auto::handler(ctx, ix)
+    }
+
+    pub fn reveal_mint<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, RevealMintCtx<'info>>, ix: RevealMintIx) -> Result<()> {
+        reveal_mint::handler(ctx, ix)
+    }
+
+    pub fn expire_pending_mint(ctx: Context<ExpirePendingMintCtx>) -> Result<()> {
+        expire_pending_mint::handler(ctx)
+    }
+
+    pub fn vrf_callback(ctx: Context<VrfCallbackCtx>) -> Result<()> {
+        vrf_callback::handler(ctx)
+    }
+
+    pub fn reveal_output_mint(ctx: Context<RevealOutputMintCtx>) -> Result<()> {
+        reveal_output_mint::handler(ctx)
+    }
+
     pub fn release_output_mint<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, ReleaseOutputMintCtx<'info>>) -> Result<()> {
         release_output_mint::handler(ctx)
     }
+
+    pub fn get_mint_entry<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, GetMintEntryCtx<'info>>, ix: GetMintEntryIx) -> Result<()> {
+        get_mint_entry::handler(ctx, ix)
+    }
+
+    pub fn get_active_phases<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, GetActivePhasesCtx<'info>>, ix: GetActivePhasesIx) -> Result<()> {
+        get_active_phases::handler(ctx, ix)
+    }
+
+    pub fn get_remaining_supply(ctx: Context<GetRemainingSupplyCtx>) -> Result<()> {
+        get_remaining_supply::handler(ctx)
+    }
 }
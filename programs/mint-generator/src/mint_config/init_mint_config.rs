@@ -9,6 +9,10 @@ pub struct InitMintConfigIx {
     output_mint_config: OutputMintConfig,
     mint_phases: Vec<MintPhase>,
     metadata: String,
+    max_per_wallet_total: Option<u64>,
+    pause_authority: Option<Pubkey>,
+    phase_selection: MintPhaseSelection,
+    bot_tax_lamports: Option<u64>,
 }
 
 #[derive(Accounts)]
@@ -29,6 +33,10 @@ pub struct InitMintConfigCtx<'info> {
 }
 
 pub fn handler(ctx: Context<InitMintConfigCtx>, ix: InitMintConfigIx) -> Result<()> {
+    let mut mint_phases = ix.mint_phases;
+    for mint_phase in mint_phases.iter_mut() {
+        mint_phase.minted = 0;
+    }
     let new_mint_config = MintConfig {
         bump: *ctx.bumps.get("mint_config").unwrap(),
         authority: ctx.accounts.authority.key(),
@@ -36,8 +44,18 @@ pub fn handler(ctx: Context<InitMintConfigCtx>, ix: InitMintConfigIx) -> Result<
         supply: 0,
         count: 0,
         output_mint_config: ix.output_mint_config,
-        mint_phases: ix.mint_phases,
+        mint_phases,
         metadata: ix.metadata,
+        pending_reveals: 0,
+        total_weight: 0,
+        provenance_hash: None,
+        entry_storage: MintEntryStorage::Inline,
+        max_per_wallet_total: ix.max_per_wallet_total,
+        paused: false,
+        pause_authority: ix.pause_authority,
+        phase_selection: ix.phase_selection,
+        bot_tax_lamports: ix.bot_tax_lamports,
+        weight_block_sums: vec![],
     };
     new_mint_config.check_valid()?;
     resize_account(
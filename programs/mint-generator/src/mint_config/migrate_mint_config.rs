@@ -0,0 +1,72 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+use std::cmp;
+
+#[derive(Accounts)]
+pub struct MigrateMintConfigCtx<'info> {
+    /// CHECK: Legacy layout is loaded in handler
+    #[account(mut)]
+    mint_config: UncheckedAccount<'info>,
+    authority: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+// converts a mint config created with the legacy layout in place
+// the account can only grow by `MAX_PERMITTED_DATA_INCREASE` per instruction so this is called until the account reaches its new size and the entries are moved
+pub fn handler(ctx: Context<MigrateMintConfigCtx>) -> Result<()> {
+    let mint_config_account_info = ctx.accounts.mint_config.to_account_info();
+    if mint_config_account_info.owner != &crate::id() {
+        return Err(error!(ErrorCode::InvalidLegacyMintConfig));
+    }
+    let (mint_config_v0, legacy_start) = {
+        let data = mint_config_account_info.try_borrow_data()?;
+        if data.len() < 8 || data[..8] != MintConfig::discriminator() {
+            return Err(error!(ErrorCode::InvalidLegacyMintConfig));
+        }
+        let mut header_data = &data[8..];
+        let mint_config_v0 = MintConfigV0::deserialize(&mut header_data).map_err(|_| error!(ErrorCode::InvalidLegacyMintConfig))?;
+        (mint_config_v0, data.len().checked_sub(header_data.len()).expect("Sub error"))
+    };
+    if mint_config_v0.authority != ctx.accounts.authority.key() {
+        return Err(error!(ErrorCode::InvalidAuthority));
+    }
+    MintConfig::seeds(&mint_config_v0.name, &mint_config_account_info.key())?;
+
+    // legacy entries directly follow the header and the account is only grown while migrating
+    let remaining_entries = usize::try_from(mint_config_v0.supply.checked_sub(mint_config_v0.count).ok_or(ErrorCode::InvalidLegacyMintConfig)?).expect("Conversion error");
+    let legacy_length = legacy_start.checked_add(remaining_entries.checked_mul(MINT_ENTRY_V0_SIZE).expect("Mul error")).expect("Add error");
+    let new_mint_config = mint_config_v0.migrate();
+    let new_length = new_mint_config.account_size();
+    let data_length = mint_config_account_info.data_len();
+    if data_length < legacy_length || data_length > new_length {
+        return Err(error!(ErrorCode::InvalidLegacyMintConfig));
+    }
+
+    if data_length < new_length {
+        resize_account(
+            &mint_config_account_info,
+            cmp::min(new_length, data_length.checked_add(MAX_PERMITTED_DATA_INCREASE).expect("Add error")),
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+        )?;
+        if mint_config_account_info.data_len() < new_length {
+            msg!("Mint config resized {} / {}", mint_config_account_info.data_len(), new_length);
+            return Ok(());
+        }
+    }
+
+    // move entries then write the new header over the legacy one
+    let mut data = mint_config_account_info.try_borrow_mut_data()?;
+    let new_start = new_length.checked_sub(new_mint_config.mint_entries_length()).expect("Sub error");
+    migrate_mint_entries(&mut data[..], legacy_start, new_start, remaining_entries);
+    let mut header_data: &mut [u8] = &mut data[..new_start];
+    new_mint_config.try_serialize(&mut header_data)?;
+    msg!("Mint config migrated");
+    Ok(())
+}
@@ -9,3 +9,18 @@ pub use close_mint_config::*;
 
 pub mod set_mint_config_metadata;
 pub use set_mint_config_metadata::*;
+
+pub mod set_mint_config_provenance_hash;
+pub use set_mint_config_provenance_hash::*;
+
+pub mod verify_mint_config_provenance_hash;
+pub use verify_mint_config_provenance_hash::*;
+
+pub mod pause_mint;
+pub use pause_mint::*;
+
+pub mod resume_mint;
+pub use resume_mint::*;
+
+pub mod migrate_mint_config;
+pub use migrate_mint_config::*;
@@ -0,0 +1,15 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct PauseMintCtx<'info> {
+    #[account(mut, constraint = mint_config.authority == authority.key() || mint_config.pause_authority == Some(authority.key()) @ ErrorCode::InvalidAuthority)]
+    mint_config: Box<Account<'info, MintConfig>>,
+    authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<PauseMintCtx>) -> Result<()> {
+    ctx.accounts.mint_config.paused = true;
+    Ok(())
+}
@@ -29,6 +29,16 @@ pub fn handler(ctx: Context<SetMintConfigMetadataCtx>, ix: SetMintConfigMetadata
         output_mint_config: ctx.accounts.mint_config.output_mint_config.clone(),
         mint_phases: ctx.accounts.mint_config.mint_phases.clone(),
         metadata: ix.metadata,
+        pending_reveals: ctx.accounts.mint_config.pending_reveals,
+        total_weight: ctx.accounts.mint_config.total_weight,
+        provenance_hash: ctx.accounts.mint_config.provenance_hash,
+        entry_storage: ctx.accounts.mint_config.entry_storage.clone(),
+        max_per_wallet_total: ctx.accounts.mint_config.max_per_wallet_total,
+        paused: ctx.accounts.mint_config.paused,
+        pause_authority: ctx.accounts.mint_config.pause_authority,
+        phase_selection: ctx.accounts.mint_config.phase_selection.clone(),
+        bot_tax_lamports: ctx.accounts.mint_config.bot_tax_lamports,
+        weight_block_sums: ctx.accounts.mint_config.weight_block_sums.clone(),
     };
     new_mint_config.check_valid()?;
     let original_data_length = ctx.accounts.mint_config.to_account_info().data_len();
@@ -0,0 +1,69 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use crate::utils::*;
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetMintConfigProvenanceHashIx {
+    provenance_hash: [u8; 32],
+}
+
+#[derive(Accounts)]
+#[instruction(ix: SetMintConfigProvenanceHashIx)]
+pub struct SetMintConfigProvenanceHashCtx<'info> {
+    #[account(mut, constraint = mint_config.authority == authority.key() @ ErrorCode::InvalidAuthority)]
+    mint_config: Box<Account<'info, MintConfig>>,
+    authority: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SetMintConfigProvenanceHashCtx>, ix: SetMintConfigProvenanceHashIx) -> Result<()> {
+    if ctx.accounts.mint_config.count > 0 || ctx.accounts.mint_config.pending_reveals > 0 {
+        return Err(error!(ErrorCode::MintingAlreadyStarted));
+    }
+    if ctx.accounts.mint_config.provenance_hash.is_some() {
+        return Err(error!(ErrorCode::ProvenanceHashSet));
+    }
+
+    let new_mint_config = MintConfig {
+        bump: ctx.accounts.mint_config.bump,
+        authority: ctx.accounts.mint_config.authority,
+        name: ctx.accounts.mint_config.name.clone(),
+        supply: ctx.accounts.mint_config.supply,
+        count: ctx.accounts.mint_config.count,
+        output_mint_config: ctx.accounts.mint_config.output_mint_config.clone(),
+        mint_phases: ctx.accounts.mint_config.mint_phases.clone(),
+        metadata: ctx.accounts.mint_config.metadata.clone(),
+        pending_reveals: ctx.accounts.mint_config.pending_reveals,
+        total_weight: ctx.accounts.mint_config.total_weight,
+        provenance_hash: Some(ix.provenance_hash),
+        entry_storage: ctx.accounts.mint_config.entry_storage.clone(),
+        max_per_wallet_total: ctx.accounts.mint_config.max_per_wallet_total,
+        paused: ctx.accounts.mint_config.paused,
+        pause_authority: ctx.accounts.mint_config.pause_authority,
+        phase_selection: ctx.accounts.mint_config.phase_selection.clone(),
+        bot_tax_lamports: ctx.accounts.mint_config.bot_tax_lamports,
+        weight_block_sums: ctx.accounts.mint_config.weight_block_sums.clone(),
+    };
+    let original_data_length = ctx.accounts.mint_config.to_account_info().data_len();
+    let new_data_length = new_mint_config.account_size();
+
+    // resize
+    resize_account(
+        &ctx.accounts.mint_config.to_account_info(),
+        new_mint_config.account_size(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    if original_data_length < new_data_length {
+        reposition_mint_entries(&ctx.accounts.mint_config, original_data_length, new_data_length)?;
+    }
+
+    // save config
+    ctx.accounts.mint_config.set_inner(new_mint_config);
+
+    Ok(())
+}
@@ -9,6 +9,10 @@ pub struct UpdateMintConfigIx {
     output_mint_config: OutputMintConfig,
     mint_phases: Vec<MintPhase>,
     metadata: String,
+    max_per_wallet_total: Option<u64>,
+    pause_authority: Option<Pubkey>,
+    phase_selection: MintPhaseSelection,
+    bot_tax_lamports: Option<u64>,
 }
 
 #[derive(Accounts)]
@@ -23,6 +27,11 @@ pub struct UpdateMintConfigCtx<'info> {
 }
 
 pub fn handler(ctx: Context<UpdateMintConfigCtx>, ix: UpdateMintConfigIx) -> Result<()> {
+    // minted counters are kept by phase index
+    let mut mint_phases = ix.mint_phases;
+    for (i, mint_phase) in mint_phases.iter_mut().enumerate() {
+        mint_phase.minted = ctx.accounts.mint_config.mint_phases.get(i).map_or(0, |p| p.minted);
+    }
     let new_mint_config = MintConfig {
         bump: ctx.accounts.mint_config.bump,
         authority: ix.authority,
@@ -30,10 +39,21 @@ pub fn handler(ctx: Context<UpdateMintConfigCtx>, ix: UpdateMintConfigIx) -> Res
         supply: ctx.accounts.mint_config.supply,
         count: ctx.accounts.mint_config.count,
         output_mint_config: ix.output_mint_config,
-        mint_phases: ix.mint_phases,
+        mint_phases,
         metadata: ix.metadata,
+        pending_reveals: ctx.accounts.mint_config.pending_reveals,
+        total_weight: ctx.accounts.mint_config.total_weight,
+        provenance_hash: ctx.accounts.mint_config.provenance_hash,
+        entry_storage: ctx.accounts.mint_config.entry_storage.clone(),
+        max_per_wallet_total: ix.max_per_wallet_total,
+        paused: ctx.accounts.mint_config.paused,
+        pause_authority: ix.pause_authority,
+        phase_selection: ix.phase_selection,
+        bot_tax_lamports: ix.bot_tax_lamports,
+        weight_block_sums: ctx.accounts.mint_config.weight_block_sums.clone(),
     };
     new_mint_config.check_valid()?;
+    ctx.accounts.mint_config.check_update(&new_mint_config)?;
 
     let original_data_length = ctx.accounts.mint_config.to_account_info().data_len();
     let new_data_length = new_mint_config.account_size();
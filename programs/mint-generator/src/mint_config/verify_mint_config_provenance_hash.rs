@@ -0,0 +1,24 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct VerifyMintConfigProvenanceHashCtx<'info> {
+    mint_config: Box<Account<'info, MintConfig>>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, VerifyMintConfigProvenanceHashCtx<'info>>) -> Result<()> {
+    // entries are only in their committed order before minting starts
+    if ctx.accounts.mint_config.count > 0 {
+        return Err(error!(ErrorCode::MintingAlreadyStarted));
+    }
+    let provenance_hash = match ctx.accounts.mint_config.provenance_hash {
+        Some(provenance_hash) => provenance_hash,
+        None => return Err(error!(ErrorCode::ProvenanceHashNotSet)),
+    };
+    if mint_entries_hash(&ctx.accounts.mint_config, &mut ctx.remaining_accounts.iter())? != provenance_hash {
+        return Err(error!(ErrorCode::ProvenanceHashMismatch));
+    }
+
+    Ok(())
+}
@@ -1,13 +1,20 @@
+use crate::conditions::ConditionContext;
+use crate::conditions::MintPhaseCondition;
 use crate::errors::ErrorCode;
-use crate::utils::pseudo_random_number;
+use crate::utils::resize_account;
 use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use arrayref::array_ref;
 use mpl_bubblegum::state::metaplex_adapter::Creator as BGCreator;
 use mpl_bubblegum::state::metaplex_adapter::TokenStandard as BGTokenStandard;
 use mpl_token_metadata::state::Creator as MPLCreator;
 use mpl_token_metadata::state::TokenStandard as MPLTokenStandard;
 use mpl_token_metadata::state::MAX_CREATOR_LIMIT;
+use solana_program::hash::hashv;
 use std::cell::RefMut;
+use std::cmp;
 use std::convert::TryFrom;
+use std::slice::Iter;
 
 pub const MINT_CONFIG_PREFIX: &str = "mint-config";
 pub const MINT_CONFIG_SIZE: usize = 8 + std::mem::size_of::<MintConfig>();
@@ -30,6 +37,26 @@ pub struct MintConfig {
     pub mint_phases: Vec<MintPhase>,
     // JSON formatted metadata string
     pub metadata: String,
+    // count of committed mints waiting to be revealed - each one reserves a remaining token
+    pub pending_reveals: u64,
+    // sum of the weights of the remaining entries
+    pub total_weight: u64,
+    // hash of the mint entries committed to by the authority - once set entries can no longer be changed
+    pub provenance_hash: Option<[u8; 32]>,
+    // how mint entries are stored at the end of the mint config
+    pub entry_storage: MintEntryStorage,
+    // if set - max tokens each user can mint across every phase tracked with a `MintWalletCounter` PDA (NOTE: the user must sign)
+    pub max_per_wallet_total: Option<u64>,
+    // minting is rejected while paused
+    pub paused: bool,
+    // if set - authority who can also pause and resume minting (NOTE: the mint config authority always can)
+    pub pause_authority: Option<Pubkey>,
+    // how `mint_auto` picks between the phases the user can mint from
+    pub phase_selection: MintPhaseSelection,
+    // if set - lamports charged to the payer instead of failing when a mint does not pass the phase, token or authorization checks
+    pub bot_tax_lamports: Option<u64>,
+    // weight of each block of `MINT_ENTRY_WEIGHT_BLOCK_SIZE` inline rows so a weighted pick only scans one block (NOTE: only kept up to date for random selection)
+    pub weight_block_sums: Vec<u64>,
 }
 
 impl MintConfig {
@@ -44,37 +71,121 @@ impl MintConfig {
     }
 
     pub fn check_valid(&self) -> Result<()> {
-        if self.output_mint_config.creators.len() > MAX_CREATOR_LIMIT - 1 {
-            return err!(ErrorCode::TooManyCreators);
+        self.output_mint_config.check_valid()?;
+        // the client can only supply the picked entry once the randomness is known
+        if matches!(self.entry_storage, MintEntryStorage::Merkle { .. })
+            && self.output_mint_config.selection_mode == MintSelectionMode::Random
+            && self.output_mint_config.randomness_source == RandomnessSource::SlotHashes
+        {
+            return err!(ErrorCode::MerkleAndRandomnessSource);
         }
-        if self.output_mint_config.merkle_tree.is_some() && (self.output_mint_config.ruleset.is_some() || self.output_mint_config.token_standard == TokenStandard::ProgrammableNonFungible) {
-            return err!(ErrorCode::ProgrammableAndMerkleTree);
+        // only the page holding the picked entry is passed so the client must know the randomness
+        if matches!(self.entry_storage, MintEntryStorage::Paged { .. })
+            && self.output_mint_config.selection_mode == MintSelectionMode::Random
+            && self.output_mint_config.randomness_source == RandomnessSource::SlotHashes
+        {
+            return err!(ErrorCode::PagedAndRandomnessSource);
+        }
+        for (mint_phase_ix, mint_phase) in self.mint_phases.iter().enumerate() {
+            mint_phase.check_valid()?;
+            // every override must produce a valid output config
+            if mint_phase.output_overrides.is_some() {
+                self.phase_output_mint_config(u8::try_from(mint_phase_ix).expect("Conversion error")).check_valid()?;
+            }
+        }
+        Ok(())
+    }
+
+    // how entries are picked and revealed cannot change once tokens are minted or committed
+    pub fn check_update(&self, new_mint_config: &MintConfig) -> Result<()> {
+        if self.count > 0 || self.pending_reveals > 0 {
+            let output_mint_config = &self.output_mint_config;
+            let new_output_mint_config = &new_mint_config.output_mint_config;
+            if output_mint_config.selection_mode != new_output_mint_config.selection_mode
+                || output_mint_config.randomness_source != new_output_mint_config.randomness_source
+                || output_mint_config.hidden_settings != new_output_mint_config.hidden_settings
+            {
+                return err!(ErrorCode::MintingAlreadyStarted);
+            }
         }
         Ok(())
     }
 
+    // output config for tokens minted from the phase with its overrides applied
+    pub fn phase_output_mint_config(&self, mint_phase_ix: u8) -> OutputMintConfig {
+        let mut output_mint_config = self.output_mint_config.clone();
+        if let Some(output_overrides) = self.mint_phases.get(usize::from(mint_phase_ix)).and_then(|p| p.output_overrides.as_ref()) {
+            if let Some(collection) = output_overrides.collection {
+                output_mint_config.collection = Some(collection);
+            }
+            if let Some(creators) = &output_overrides.creators {
+                output_mint_config.creators = creators.clone();
+            }
+            if let Some(seller_fee_basis_points) = output_overrides.seller_fee_basis_points {
+                output_mint_config.seller_fee_basis_points = seller_fee_basis_points;
+            }
+            if let Some(token_standard) = &output_overrides.token_standard {
+                output_mint_config.token_standard = token_standard.clone();
+            }
+            if let Some(ruleset) = output_overrides.ruleset {
+                output_mint_config.ruleset = Some(ruleset);
+            }
+            if let Some(release_authority) = output_overrides.release_authority {
+                output_mint_config.release_authority = Some(release_authority);
+            }
+        }
+        output_mint_config
+    }
+
     pub fn remaining_tokens(&self) -> u64 {
         self.supply.checked_sub(self.count).expect("Sub error")
     }
 
+    pub fn available_tokens(&self) -> u64 {
+        self.remaining_tokens().saturating_sub(self.pending_reveals)
+    }
+
+    pub fn mint_entry_size(&self) -> usize {
+        match self.entry_storage {
+            MintEntryStorage::Inline => MINT_ENTRY_SIZE,
+            MintEntryStorage::Template { .. } => MINT_ENTRY_TEMPLATE_INDEX_SIZE,
+            // entries are stored in `MintEntryPage` accounts
+            MintEntryStorage::Paged { .. } => 0,
+            // entries are supplied by the client and only a consumed bitmap is stored
+            MintEntryStorage::Merkle { .. } => 0,
+        }
+    }
+
+    // length of the mint entries data stored at the end of the account - the remaining entries are always last
+    pub fn mint_entries_length(&self) -> usize {
+        let stored_entries = match (&self.entry_storage, &self.output_mint_config.selection_mode) {
+            // consumed entries stay in the bitmap
+            (MintEntryStorage::Merkle { .. }, _) => return merkle_bitmap_length(self.supply),
+            // consumed entries stay ahead of the remaining ones so the next entry never has to be moved
+            (_, MintSelectionMode::Sequential) => self.supply,
+            _ => self.remaining_tokens(),
+        };
+        usize::try_from(stored_entries).expect("Conversion error").checked_mul(self.mint_entry_size()).expect("Mul error")
+    }
+
     pub fn account_size(&self) -> usize {
         self.try_to_vec()
             .expect("Error getting account size")
             .len()
-            .checked_add(usize::try_from(self.remaining_tokens()).expect("Conversion error").checked_mul(MINT_ENTRY_SIZE).expect("Mul error"))
+            .checked_add(self.mint_entries_length())
             .expect("Add error")
             .checked_add(8)
             .expect("Add error")
     }
 }
 
-pub fn output_mint_mpl_creators(mint_config: &Account<MintConfig>) -> Vec<MPLCreator> {
+pub fn output_mint_mpl_creators(mint_config: &Account<MintConfig>, output_mint_config: &OutputMintConfig) -> Vec<MPLCreator> {
     let mut creators = vec![MPLCreator {
         address: mint_config.key(),
         verified: true,
         share: 0,
     }];
-    for creator in &mint_config.output_mint_config.creators {
+    for creator in &output_mint_config.creators {
         creators.push(MPLCreator {
             address: creator.address,
             share: creator.share,
@@ -84,13 +195,13 @@ pub fn output_mint_mpl_creators(mint_config: &Account<MintConfig>) -> Vec<MPLCre
     creators
 }
 
-pub fn output_mint_bg_creators(mint_config: &Account<MintConfig>) -> Vec<BGCreator> {
+pub fn output_mint_bg_creators(mint_config: &Account<MintConfig>, output_mint_config: &OutputMintConfig) -> Vec<BGCreator> {
     let mut creators = vec![BGCreator {
         address: mint_config.key(),
         verified: true,
         share: 0,
     }];
-    for creator in &mint_config.output_mint_config.creators {
+    for creator in &output_mint_config.creators {
         creators.push(BGCreator {
             address: creator.address,
             share: creator.share,
@@ -100,104 +211,659 @@ pub fn output_mint_bg_creators(mint_config: &Account<MintConfig>) -> Vec<BGCreat
     creators
 }
 
+// outcome of a mint check - failures are returned instead of raised so they can be charged the bot tax
+pub enum MintValidation<T> {
+    Valid(T),
+    Invalid(ErrorCode),
+}
+
+impl<T> MintValidation<T> {
+    pub fn into_result(self) -> Result<T> {
+        match self {
+            MintValidation::Valid(value) => Ok(value),
+            MintValidation::Invalid(error_code) => Err(error!(error_code)),
+        }
+    }
+}
+
 pub fn get_phase(mint_config: &MintConfig, phase_ix: u8, is_authority: bool) -> Result<&MintPhase> {
-    let timestamp = Clock::get()?.unix_timestamp;
+    validate_phase(mint_config, phase_ix, is_authority)?.into_result()
+}
+
+pub fn validate_phase(mint_config: &MintConfig, phase_ix: u8, is_authority: bool) -> Result<MintValidation<&MintPhase>> {
+    let clock = Clock::get()?;
     let mint_phase = match mint_config.mint_phases.get(usize::try_from(phase_ix).expect("Conversion error")) {
         Some(mint_phase) => mint_phase,
         _ => return Err(error!(ErrorCode::InvalidPhase)),
     };
+    let condition_context = ConditionContext {
+        timestamp: clock.unix_timestamp,
+        slot: clock.slot,
+        count: mint_config.count,
+        mint_phases: &mint_config.mint_phases,
+    };
 
-    // check start condition
-    if let Some(start_condition) = &mint_phase.start_condition {
-        let mut started = false;
-        if let Some(time_seconds) = start_condition.time_seconds {
-            if timestamp >= time_seconds {
-                started = true;
-            }
-        }
-        if let Some(count) = start_condition.count {
-            if mint_config.count >= count {
-                started = true
+    Ok(match mint_phase.check_active(&condition_context, is_authority) {
+        MintValidation::Valid(()) => MintValidation::Valid(mint_phase),
+        MintValidation::Invalid(error_code) => MintValidation::Invalid(error_code),
+    })
+}
+
+pub fn get_random_output_mint<'info>(
+    mint_config: &mut Account<'info, MintConfig>,
+    random_number: u64,
+    merkle_mint_entry: Option<MerkleMintEntry>,
+    remaining_accounts: &mut Iter<AccountInfo<'info>>,
+) -> Result<MintEntry> {
+    let remaining_tokens = mint_config.remaining_tokens();
+    if remaining_tokens == 0 {
+        return Err(error!(ErrorCode::NotTokensRemaining));
+    }
+    if let MintEntryStorage::Paged { .. } = mint_config.entry_storage {
+        return get_paged_output_mint(mint_config, random_number, remaining_accounts);
+    }
+    if let MintEntryStorage::Merkle { root } = mint_config.entry_storage {
+        return get_merkle_output_mint(mint_config, &root, random_number, merkle_mint_entry);
+    }
+    let index = match mint_config.output_mint_config.selection_mode {
+        // every entry has a weight of 1
+        MintSelectionMode::Random if mint_config.total_weight == remaining_tokens => random_number.checked_rem(remaining_tokens).ok_or(ErrorCode::InvalidIndex)? as usize,
+        MintSelectionMode::Random => get_weighted_index(mint_config, random_number.checked_rem(mint_config.total_weight).ok_or(ErrorCode::InvalidIndex)?)?,
+        // entries are kept in insertion order so the next one is always first
+        MintSelectionMode::Sequential => 0,
+    };
+
+    get_output_mint(mint_config, index)
+}
+
+pub fn get_merkle_output_mint(mint_config: &mut Account<MintConfig>, root: &[u8; 32], random_number: u64, merkle_mint_entry: Option<MerkleMintEntry>) -> Result<MintEntry> {
+    let merkle_mint_entry = merkle_mint_entry.ok_or(ErrorCode::InvalidMerkleMintEntry)?;
+    let target = match mint_config.output_mint_config.selection_mode {
+        MintSelectionMode::Random => random_number.checked_rem(mint_config.remaining_tokens()).ok_or(ErrorCode::InvalidIndex)?,
+        MintSelectionMode::Sequential => 0,
+    };
+
+    let mint_config_account_info = mint_config.to_account_info();
+    let mut mint_config_data = mint_config_account_info.data.borrow_mut();
+    let bitmap_start = mint_config_account_info.data_len().checked_sub(merkle_bitmap_length(mint_config.supply)).expect("Sub error");
+    let bitmap = &mut mint_config_data[bitmap_start..];
+
+    // the client picks its entry from the same randomness so it must match the unconsumed index on chain
+    let index = merkle_unconsumed_index(bitmap, mint_config.supply, target)?;
+    if merkle_mint_entry.index != index {
+        return Err(error!(ErrorCode::InvalidMerkleMintEntry));
+    }
+    let mint_entry = new_mint_entry(merkle_mint_entry.name, merkle_mint_entry.symbol, merkle_mint_entry.uri, None)?;
+    if !verify_merkle_proof(&merkle_mint_entry.proof, root, merkle_leaf(index, &mint_entry)?) {
+        return Err(error!(ErrorCode::InvalidMerkleProof));
+    }
+
+    // consume
+    bitmap[(index / 8) as usize] |= 1 << (index % 8);
+    mint_config.count = mint_config.count.saturating_add(1);
+    mint_config.total_weight = mint_config.total_weight.saturating_sub(1);
+
+    Ok(mint_entry)
+}
+
+// index of the `target`th unconsumed entry in the bitmap
+pub fn merkle_unconsumed_index(bitmap: &[u8], supply: u64, target: u64) -> Result<u64> {
+    let mut remaining_target = target;
+    for (byte_index, byte) in bitmap.iter().enumerate() {
+        let first_index = (byte_index as u64).checked_mul(8).expect("Mul error");
+        // bits past the supply in the last byte count as consumed
+        let valid_bits = cmp::min(8, supply.saturating_sub(first_index)) as u32;
+        let unconsumed_mask = if valid_bits == 8 { !byte } else { !byte & ((1u8 << valid_bits) - 1) };
+        let unconsumed = unconsumed_mask.count_ones() as u64;
+        if remaining_target < unconsumed {
+            let mut mask = unconsumed_mask;
+            for _ in 0..remaining_target {
+                // clear lowest unconsumed bit
+                mask &= mask - 1;
             }
+            return Ok(first_index.checked_add(mask.trailing_zeros() as u64).expect("Add error"));
         }
-        if !started && !is_authority {
-            return Err(error!(ErrorCode::PhaseNotActive));
-        }
+        remaining_target = remaining_target.checked_sub(unconsumed).expect("Sub error");
     }
+    Err(error!(ErrorCode::InvalidIndex))
+}
 
-    // check end condition
-    if let Some(end_condition) = &mint_phase.end_condition {
-        let mut ended = false;
-        if let Some(time_seconds) = end_condition.time_seconds {
-            if timestamp >= time_seconds {
-                ended = true;
-            }
+// leaves and nodes are domain separated and node pairs are sorted so proofs do not need directions
+pub fn merkle_leaf(index: u64, mint_entry: &MintEntry) -> Result<[u8; 32]> {
+    Ok(hashv(&[
+        &[0],
+        &index.to_le_bytes(),
+        &mint_entry.name.try_to_vec()?,
+        &mint_entry.symbol.try_to_vec()?,
+        &mint_entry.uri.try_to_vec()?,
+    ])
+    .to_bytes())
+}
+
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed_root = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&[1], &node, sibling]).to_bytes()
+        } else {
+            hashv(&[&[1], sibling, &node]).to_bytes()
         }
-        if let Some(count) = end_condition.count {
-            if mint_config.count >= count {
-                ended = true
-            }
+    });
+    &computed_root == root
+}
+
+pub fn merkle_bitmap_length(supply: u64) -> usize {
+    usize::try_from(supply).expect("Conversion error").checked_add(7).expect("Add error") / 8
+}
+
+pub fn get_weighted_index(mint_config: &Account<MintConfig>, target_weight: u64) -> Result<usize> {
+    let mint_config_account_info = mint_config.to_account_info();
+    let mint_config_data = mint_config_account_info.data.borrow();
+    let remaining_tokens = usize::try_from(mint_config.remaining_tokens()).expect("Conversion error");
+    let mint_entries_start = mint_config_account_info
+        .data_len()
+        .checked_sub(remaining_tokens.checked_mul(MINT_ENTRY_SIZE).expect("Mul error"))
+        .expect("Sub error");
+    weighted_index(&mint_config_data, mint_entries_start, remaining_tokens, &mint_config.weight_block_sums, target_weight)
+}
+
+// walks the block sums to the block holding the target and then only the rows of that block
+pub fn weighted_index(data: &[u8], mint_entries_start: usize, remaining_entries: usize, weight_block_sums: &[u64], target_weight: u64) -> Result<usize> {
+    let mut remaining_target = target_weight;
+    for (block_index, block_weight) in weight_block_sums.iter().enumerate() {
+        if remaining_target >= *block_weight {
+            remaining_target = remaining_target.checked_sub(*block_weight).expect("Sub error");
+            continue;
         }
-        if ended {
-            return Err(error!(ErrorCode::PhaseNotActive));
+        let block_start = block_index.checked_mul(MINT_ENTRY_WEIGHT_BLOCK_SIZE).expect("Mul error");
+        let block_end = cmp::min(block_start.checked_add(MINT_ENTRY_WEIGHT_BLOCK_SIZE).expect("Add error"), remaining_entries);
+        let mut cumulative_weight: u64 = 0;
+        for index in block_start..block_end {
+            cumulative_weight = cumulative_weight.checked_add(mint_entry_weight(data, mint_entries_start, index) as u64).expect("Add error");
+            if remaining_target < cumulative_weight {
+                return Ok(index);
+            }
         }
+        break;
     }
+    Err(error!(ErrorCode::InvalidIndex))
+}
 
-    Ok(mint_phase)
+pub fn mint_entry_weight(data: &[u8], mint_entries_start: usize, index: usize) -> u16 {
+    let weight_position = mint_entries_start
+        .checked_add(index.checked_add(1).expect("Add error").checked_mul(MINT_ENTRY_SIZE).expect("Mul error"))
+        .expect("Add error")
+        .checked_sub(MINT_ENTRY_WEIGHT_LENGTH)
+        .expect("Sub error");
+    u16::from_le_bytes(*array_ref![data, weight_position, MINT_ENTRY_WEIGHT_LENGTH])
 }
 
-pub fn get_random_output_mint<'info>(mint_config: &mut Account<'info, MintConfig>, recent_slothashes: &AccountInfo<'info>) -> Result<MintEntry> {
-    let remaining_tokens = mint_config.remaining_tokens();
-    if remaining_tokens == 0 {
-        return Err(error!(ErrorCode::NotTokensRemaining));
+pub fn weight_block_count(entries: u64) -> usize {
+    usize::try_from(entries).expect("Conversion error").checked_add(MINT_ENTRY_WEIGHT_BLOCK_SIZE - 1).expect("Add error") / MINT_ENTRY_WEIGHT_BLOCK_SIZE
+}
+
+// block sums of `entries` rows that all have a weight of 1
+pub fn unit_weight_block_sums(entries: u64) -> Vec<u64> {
+    (0..weight_block_count(entries))
+        .map(|block_index| cmp::min(MINT_ENTRY_WEIGHT_BLOCK_SIZE as u64, entries.saturating_sub((block_index * MINT_ENTRY_WEIGHT_BLOCK_SIZE) as u64)))
+        .collect()
+}
+
+pub fn weight_block_sums_of(data: &[u8], mint_entries_start: usize, entries: u64) -> Vec<u64> {
+    let mut weight_block_sums = vec![0; weight_block_count(entries)];
+    for index in 0..usize::try_from(entries).expect("Conversion error") {
+        let block_index = index / MINT_ENTRY_WEIGHT_BLOCK_SIZE;
+        weight_block_sums[block_index] = weight_block_sums[block_index]
+            .checked_add(mint_entry_weight(data, mint_entries_start, index) as u64)
+            .expect("Add error");
     }
-    let pseudo_random_index = pseudo_random_number(recent_slothashes)?.checked_rem(remaining_tokens).ok_or(ErrorCode::InvalidIndex)? as usize;
+    weight_block_sums
+}
 
-    get_output_mint(mint_config, pseudo_random_index)
+// replaces the block sums and moves the entries when the header changes size
+pub fn set_weight_block_sums<'info>(mint_config: &mut Account<'info, MintConfig>, weight_block_sums: Vec<u64>, payer: &AccountInfo<'info>, system_program: &AccountInfo<'info>) -> Result<()> {
+    let original_data_length = mint_config.to_account_info().data_len();
+    mint_config.weight_block_sums = weight_block_sums;
+    let new_data_length = mint_config.account_size();
+    if original_data_length > new_data_length {
+        reposition_mint_entries(mint_config, original_data_length, new_data_length)?;
+    }
+    if original_data_length != new_data_length {
+        resize_account(&mint_config.to_account_info(), new_data_length, payer, system_program)?;
+    }
+    if original_data_length < new_data_length {
+        reposition_mint_entries(mint_config, original_data_length, new_data_length)?;
+    }
+    Ok(())
 }
 
 pub fn get_output_mint(mint_config: &mut Account<MintConfig>, index: usize) -> Result<MintEntry> {
+    let template = match &mint_config.entry_storage {
+        MintEntryStorage::Inline => None,
+        MintEntryStorage::Template { template } => Some(template.clone()),
+        MintEntryStorage::Paged { .. } | MintEntryStorage::Merkle { .. } => return Err(error!(ErrorCode::InvalidMintEntryStorage)),
+    };
+    if let Some(template) = template {
+        return get_template_output_mint(mint_config, &template, index);
+    }
+
     // unpack mint config
     let mint_config_account_info = mint_config.to_account_info();
-    let mint_config_data_pointer = &mint_config_account_info.data;
+    let remaining_tokens = usize::try_from(mint_config.remaining_tokens()).expect("Conversion error");
     let mint_entries_start = mint_config_account_info
         .data_len()
-        .checked_sub(
-            (usize::try_from(mint_config.remaining_tokens()).expect("Conversion error"))
-                .checked_mul(MINT_ENTRY_SIZE)
-                .expect("Mul error"),
-        )
+        .checked_sub(remaining_tokens.checked_mul(MINT_ENTRY_SIZE).expect("Mul error"))
         .expect("Sub error");
 
+    // the last row is swapped into the picked row
+    if mint_config.output_mint_config.selection_mode == MintSelectionMode::Random {
+        let mint_config_data = mint_config_account_info.data.borrow();
+        let last_index = remaining_tokens.checked_sub(1).expect("Sub error");
+        let weight = mint_entry_weight(&mint_config_data, mint_entries_start, index) as u64;
+        let last_weight = mint_entry_weight(&mint_config_data, mint_entries_start, last_index) as u64;
+        let weight_block_sums = &mut mint_config.weight_block_sums;
+        if let Some(block_weight) = weight_block_sums.get_mut(index / MINT_ENTRY_WEIGHT_BLOCK_SIZE) {
+            *block_weight = block_weight.saturating_sub(weight).saturating_add(last_weight);
+        }
+        if let Some(block_weight) = weight_block_sums.get_mut(last_index / MINT_ENTRY_WEIGHT_BLOCK_SIZE) {
+            *block_weight = block_weight.saturating_sub(last_weight);
+        }
+    }
+    let mint_entry = take_mint_entry(&mint_config_account_info, mint_entries_start, remaining_tokens, index, &mint_config.output_mint_config.selection_mode)?;
+    mint_config.count = mint_config.count.saturating_add(1);
+    mint_config.total_weight = mint_config.total_weight.saturating_sub(mint_entry.weight as u64);
+
+    Ok(mint_entry)
+}
+
+// removes the entry at `index` from the `remaining_entries` rows starting at `mint_entries_start`
+pub fn take_mint_entry(account_info: &AccountInfo, mint_entries_start: usize, remaining_entries: usize, index: usize, selection_mode: &MintSelectionMode) -> Result<MintEntry> {
+    let data_pointer = &account_info.data;
+
     // get entry
     let start_position = mint_entries_start.checked_add(index.checked_mul(MINT_ENTRY_SIZE).expect("Mul error")).expect("Add error");
     let end_position = start_position.checked_add(MINT_ENTRY_SIZE).expect("Add error");
-    let entry_slice = get_slice_of_data(&mint_config_data_pointer.borrow_mut(), start_position, end_position);
+    let entry_slice = get_slice_of_data(&data_pointer.borrow_mut(), start_position, end_position);
 
     // deserialize entry
-    let name = std::str::from_utf8(&entry_slice[0..MAX_NAME_LENGTH][4..]).unwrap().to_string().replace("\\0", "");
-    let symbol = std::str::from_utf8(&entry_slice[MAX_NAME_LENGTH..MAX_NAME_LENGTH + MAX_SYMBOL_LENGTH][4..])
-        .unwrap()
-        .to_string()
-        .replace("\\0", "");
-    let uri = std::str::from_utf8(&entry_slice[MAX_NAME_LENGTH + MAX_SYMBOL_LENGTH..MAX_NAME_LENGTH + MAX_SYMBOL_LENGTH + MAX_URI_LENGTH][4..])
-        .unwrap()
-        .to_string()
-        .replace("\\0", "");
-
-    // get last entry
-    let last_entry_index = mint_config.supply.saturating_sub(mint_config.count).saturating_sub(1);
-    let last_entry_start_position = mint_entries_start + (usize::try_from(last_entry_index).expect("Could not cast into usize")) * MINT_ENTRY_SIZE;
-    let last_entry_end_position = last_entry_start_position + MINT_ENTRY_SIZE;
-    let last_entry_slice = get_slice_of_data(&mint_config_data_pointer.borrow_mut(), last_entry_start_position, last_entry_end_position);
-
-    // move last entry up
-    let mut mint_config_data = mint_config_data_pointer.borrow_mut();
-    let used_entry = &mut mint_config_data[start_position..end_position];
-    used_entry.copy_from_slice(&last_entry_slice);
+    let mint_entry = MintEntry::decode(&entry_slice)?;
+
+    match selection_mode {
+        MintSelectionMode::Random => {
+            // get last entry
+            let last_entry_index = remaining_entries.saturating_sub(1);
+            let last_entry_start_position = mint_entries_start + last_entry_index * MINT_ENTRY_SIZE;
+            let last_entry_end_position = last_entry_start_position + MINT_ENTRY_SIZE;
+            let last_entry_slice = get_slice_of_data(&data_pointer.borrow_mut(), last_entry_start_position, last_entry_end_position);
+
+            // move last entry up
+            let mut data = data_pointer.borrow_mut();
+            let used_entry = &mut data[start_position..end_position];
+            used_entry.copy_from_slice(&last_entry_slice);
+        }
+        // the entry is the first remaining one and stays in place as a consumed row - the caller advances the count past it
+        MintSelectionMode::Sequential => {}
+    }
+
+    Ok(mint_entry)
+}
+
+pub fn get_paged_output_mint<'info>(mint_config: &mut Account<'info, MintConfig>, random_number: u64, remaining_accounts: &mut Iter<AccountInfo<'info>>) -> Result<MintEntry> {
+    let target_index = match mint_config.output_mint_config.selection_mode {
+        MintSelectionMode::Random => random_number.checked_rem(mint_config.remaining_tokens()).ok_or(ErrorCode::InvalidIndex)?,
+        // pages and the entries in them are kept in insertion order
+        MintSelectionMode::Sequential => 0,
+    };
+    let (page_index, index) = mint_entry_page_position(mint_config, target_index)?;
+    let mut mint_entry_page = load_mint_entry_page(mint_config, page_index, remaining_accounts)?;
+
+    // rows are not resized while minting so the remaining ones only depend on the page count
+    let mint_entry = take_mint_entry(
+        &mint_entry_page.to_account_info(),
+        mint_entry_page.remaining_entries_start(&mint_config.output_mint_config.selection_mode),
+        mint_entry_page.remaining_entries(),
+        index,
+        &mint_config.output_mint_config.selection_mode,
+    )?;
+    mint_entry_page.count = mint_entry_page.count.checked_add(1).expect("Add error");
+    mint_entry_page.exit(&crate::id())?;
+    if let MintEntryStorage::Paged { page_remaining } = &mut mint_config.entry_storage {
+        page_remaining[page_index as usize] = page_remaining[page_index as usize].checked_sub(1).expect("Sub error");
+    }
+    mint_config.count = mint_config.count.saturating_add(1);
+    mint_config.total_weight = mint_config.total_weight.saturating_sub(mint_entry.weight as u64);
+
+    Ok(mint_entry)
+}
+
+// page holding the `target_index`th remaining entry and the entry index in the page - only that page needs to be passed
+pub fn mint_entry_page_position(mint_config: &MintConfig, target_index: u64) -> Result<(u32, usize)> {
+    let page_remaining = match &mint_config.entry_storage {
+        MintEntryStorage::Paged { page_remaining } => page_remaining,
+        _ => return Err(error!(ErrorCode::InvalidMintEntryStorage)),
+    };
+    let mut cumulative_remaining: u64 = 0;
+    for (page_index, remaining) in page_remaining.iter().enumerate() {
+        let next_cumulative_remaining = cumulative_remaining.checked_add(*remaining as u64).expect("Add error");
+        if target_index < next_cumulative_remaining {
+            let index = usize::try_from(target_index.checked_sub(cumulative_remaining).expect("Sub error")).expect("Conversion error");
+            return Ok((u32::try_from(page_index).expect("Conversion error"), index));
+        }
+        cumulative_remaining = next_cumulative_remaining;
+    }
+    Err(error!(ErrorCode::InvalidIndex))
+}
+
+pub fn load_mint_entry_page<'info>(mint_config: &Account<'info, MintConfig>, page_index: u32, remaining_accounts: &mut Iter<AccountInfo<'info>>) -> Result<Account<'info, MintEntryPage>> {
+    let mint_entry_page = Account::<MintEntryPage>::try_from(next_account_info(remaining_accounts)?)?;
+    if mint_entry_page.mint_config != mint_config.key() || mint_entry_page.page_index != page_index {
+        return Err(error!(ErrorCode::InvalidMintEntryPage));
+    }
+    Ok(mint_entry_page)
+}
+
+// reads the `index`th remaining entry without removing it
+pub fn read_mint_entry<'info>(mint_config: &Account<'info, MintConfig>, index: u64, remaining_accounts: &mut Iter<AccountInfo<'info>>) -> Result<MintEntry> {
+    let remaining_tokens = mint_config.remaining_tokens();
+    if index >= remaining_tokens {
+        return Err(error!(ErrorCode::InvalidIndex));
+    }
+    let mint_config_account_info = mint_config.to_account_info();
+    match &mint_config.entry_storage {
+        MintEntryStorage::Inline => {
+            let mint_config_data = mint_config_account_info.data.borrow_mut();
+            let start_position = mint_config_account_info
+                .data_len()
+                .checked_sub((remaining_tokens as usize).checked_mul(MINT_ENTRY_SIZE).expect("Mul error"))
+                .expect("Sub error")
+                .checked_add((index as usize).checked_mul(MINT_ENTRY_SIZE).expect("Mul error"))
+                .expect("Add error");
+            let entry_slice = get_slice_of_data(&mint_config_data, start_position, start_position + MINT_ENTRY_SIZE);
+            MintEntry::decode(&entry_slice)
+        }
+        MintEntryStorage::Template { template } => {
+            let mint_config_data = mint_config_account_info.data.borrow_mut();
+            let position = mint_config_account_info
+                .data_len()
+                .checked_sub((remaining_tokens as usize).checked_mul(MINT_ENTRY_TEMPLATE_INDEX_SIZE).expect("Mul error"))
+                .expect("Sub error")
+                .checked_add((index as usize).checked_mul(MINT_ENTRY_TEMPLATE_INDEX_SIZE).expect("Mul error"))
+                .expect("Add error");
+            let entry_index = template_entry_index(&mint_config_data, position, template_unmoved_index(mint_config, index as usize));
+            Ok(template.render(entry_index.checked_add(template.index_offset).expect("Add error")))
+        }
+        MintEntryStorage::Paged { .. } => {
+            let (page_index, page_entry_index) = mint_entry_page_position(mint_config, index)?;
+            let mint_entry_page = load_mint_entry_page(mint_config, page_index, remaining_accounts)?;
+            let mint_entry_page_info = mint_entry_page.to_account_info();
+            let mint_entry_page_data = mint_entry_page_info.data.borrow_mut();
+            let start_position = mint_entry_page
+                .remaining_entries_start(&mint_config.output_mint_config.selection_mode)
+                .checked_add(page_entry_index.checked_mul(MINT_ENTRY_SIZE).expect("Mul error"))
+                .expect("Add error");
+            let entry_slice = get_slice_of_data(&mint_entry_page_data, start_position, start_position + MINT_ENTRY_SIZE);
+            MintEntry::decode(&entry_slice)
+        }
+        // only the root of the entries is stored
+        MintEntryStorage::Merkle { .. } => Err(error!(ErrorCode::InvalidMintEntryStorage)),
+    }
+}
+
+pub fn new_mint_entry(name: String, symbol: String, uri: String, weight: Option<u16>) -> Result<MintEntry> {
+    let weight = weight.unwrap_or(1);
+    if weight == 0 {
+        return Err(error!(ErrorCode::InvalidMintEntryWeight));
+    }
+    let mint_entry = MintEntry { name, symbol, uri, weight };
+    // fails early on fields that do not fit in the row
+    mint_entry.encode()?;
+    Ok(mint_entry)
+}
+
+pub fn set_mint_entries<'info>(
+    mint_config: &mut Account<'info, MintConfig>,
+    start_index: u64,
+    mint_entries: Vec<MintEntry>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    remaining_accounts: &mut Iter<AccountInfo<'info>>,
+) -> Result<()> {
+    // committed mints pick from the entries on reveal so they cannot change under them
+    if mint_config.count > 0 || mint_config.pending_reveals > 0 {
+        return Err(error!(ErrorCode::MintingAlreadyStarted));
+    }
+    if mint_config.provenance_hash.is_some() {
+        return Err(error!(ErrorCode::ProvenanceHashSet));
+    }
+
+    match mint_config.entry_storage {
+        MintEntryStorage::Inline => {
+            let mint_config_account_info = mint_config.to_account_info();
+            let mint_entries_start = mint_config_account_info
+                .data_len()
+                .checked_sub((mint_config.supply as usize).checked_mul(MINT_ENTRY_SIZE).expect("Mul error"))
+                .expect("Sub error");
+            let replaced_weights = write_mint_entries(&mint_config_account_info, mint_entries_start, mint_config.supply, start_index, &mint_entries, payer, system_program)?;
+
+            // update supply and weight
+            let replaced_weight: u64 = replaced_weights.iter().map(|w| *w as u64).sum();
+            let added_weight: u64 = mint_entries.iter().map(|e| e.weight as u64).sum();
+            mint_config.supply = cmp::max(mint_config.supply, start_index.checked_add(mint_entries.len() as u64).expect("Add error"));
+            mint_config.total_weight = mint_config.total_weight.checked_sub(replaced_weight).expect("Sub error").checked_add(added_weight).expect("Add error");
+
+            // update the block of each written row
+            let mut weight_block_sums = mint_config.weight_block_sums.clone();
+            weight_block_sums.resize(weight_block_count(mint_config.supply), 0);
+            for (i, mint_entry) in mint_entries.iter().enumerate() {
+                let block_index = usize::try_from(start_index).expect("Conversion error").checked_add(i).expect("Add error") / MINT_ENTRY_WEIGHT_BLOCK_SIZE;
+                let replaced_weight = replaced_weights.get(i).map_or(0, |w| *w as u64);
+                weight_block_sums[block_index] = weight_block_sums[block_index]
+                    .checked_sub(replaced_weight)
+                    .expect("Sub error")
+                    .checked_add(mint_entry.weight as u64)
+                    .expect("Add error");
+            }
+            set_weight_block_sums(mint_config, weight_block_sums, payer, system_program)?;
+        }
+        MintEntryStorage::Paged { .. } => {
+            // pages are picked uniformly so entries cannot be weighted
+            if mint_entries.iter().any(|e| e.weight != 1) {
+                return Err(error!(ErrorCode::InvalidMintEntryWeight));
+            }
+            let page_index = u32::try_from(start_index / MINT_ENTRY_PAGE_CAPACITY as u64).expect("Conversion error");
+            let page_start_index = start_index % MINT_ENTRY_PAGE_CAPACITY as u64;
+            if page_start_index.checked_add(mint_entries.len() as u64).expect("Add error") > MINT_ENTRY_PAGE_CAPACITY as u64 {
+                return Err(error!(ErrorCode::InvalidIndex));
+            }
+
+            let mint_entry_page_info = next_account_info(remaining_accounts)?;
+            let mut mint_entry_page = Account::<MintEntryPage>::try_from(mint_entry_page_info)?;
+            if mint_entry_page.mint_config != mint_config.key() || mint_entry_page.page_index != page_index {
+                return Err(error!(ErrorCode::InvalidMintEntryPage));
+            }
+            let page_supply = mint_entry_page.supply as u64;
+            write_mint_entries(mint_entry_page_info, MINT_ENTRY_PAGE_HEADER_SIZE, page_supply, page_start_index, &mint_entries, payer, system_program)?;
+
+            // update page and config supply
+            let new_page_supply = cmp::max(page_supply, page_start_index.checked_add(mint_entries.len() as u64).expect("Add error"));
+            let added_supply = new_page_supply.checked_sub(page_supply).expect("Sub error");
+            mint_entry_page.supply = u32::try_from(new_page_supply).expect("Conversion error");
+            mint_entry_page.exit(&crate::id())?;
+            if let MintEntryStorage::Paged { page_remaining } = &mut mint_config.entry_storage {
+                page_remaining[page_index as usize] = page_remaining[page_index as usize]
+                    .checked_add(u32::try_from(added_supply).expect("Conversion error"))
+                    .expect("Add error");
+            }
+            mint_config.supply = mint_config.supply.checked_add(added_supply).expect("Add error");
+            mint_config.total_weight = mint_config.total_weight.checked_add(added_supply).expect("Add error");
+        }
+        MintEntryStorage::Template { .. } | MintEntryStorage::Merkle { .. } => return Err(error!(ErrorCode::InvalidMintEntryStorage)),
+    }
+    Ok(())
+}
+
+// writes a run of entries into the `supply` rows starting at `mint_entries_start` and returns the weights of the entries replaced in order
+pub fn write_mint_entries<'info>(
+    account_info: &AccountInfo<'info>,
+    mint_entries_start: usize,
+    supply: u64,
+    start_index: u64,
+    mint_entries: &[MintEntry],
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<Vec<u16>> {
+    // entries can only replace existing rows or extend them without leaving a gap of empty rows
+    if start_index > supply {
+        return Err(error!(ErrorCode::InvalidIndex));
+    }
+    let start_position = mint_entries_start
+        .checked_add((start_index as usize).checked_mul(MINT_ENTRY_SIZE).expect("Mul error"))
+        .expect("Add error");
+    let end_position = start_position.checked_add(mint_entries.len().checked_mul(MINT_ENTRY_SIZE).expect("Mul error")).expect("Add error");
+    if account_info.data_len() < end_position {
+        resize_account(account_info, end_position, payer, system_program)?;
+    }
+
+    let mut data = account_info.data.borrow_mut();
+    let mut replaced_weights = Vec::new();
+    for (i, mint_entry) in mint_entries.iter().enumerate() {
+        let entry_start_position = start_position.checked_add(i.checked_mul(MINT_ENTRY_SIZE).expect("Mul error")).expect("Add error");
+        let entry_end_position = entry_start_position.checked_add(MINT_ENTRY_SIZE).expect("Add error");
+        if start_index.checked_add(i as u64).expect("Add error") < supply {
+            // replacing an existing entry
+            replaced_weights.push(u16::from_le_bytes(*array_ref![data, entry_end_position - MINT_ENTRY_WEIGHT_LENGTH, MINT_ENTRY_WEIGHT_LENGTH]));
+        }
+        data[entry_start_position..entry_end_position].copy_from_slice(&mint_entry.encode()?);
+    }
+    Ok(replaced_weights)
+}
+
+// removes entries in `start_index..end_index` and compacts the entries after them - only before minting while entries are in the order they were set
+pub fn remove_mint_entries<'info>(mint_config: &mut Account<'info, MintConfig>, start_index: u64, end_index: u64, collector: &AccountInfo<'info>, system_program: &AccountInfo<'info>) -> Result<()> {
+    // committed mints pick from the entries on reveal so they cannot change under them
+    if mint_config.count > 0 || mint_config.pending_reveals > 0 {
+        return Err(error!(ErrorCode::MintingAlreadyStarted));
+    }
+    if mint_config.provenance_hash.is_some() {
+        return Err(error!(ErrorCode::ProvenanceHashSet));
+    }
+    if start_index >= end_index || end_index > mint_config.supply {
+        return Err(error!(ErrorCode::InvalidIndex));
+    }
+    if matches!(mint_config.entry_storage, MintEntryStorage::Paged { .. } | MintEntryStorage::Merkle { .. }) {
+        return Err(error!(ErrorCode::InvalidMintEntryStorage));
+    }
+
+    let mint_entry_size = mint_config.mint_entry_size();
+    let mint_config_account_info = mint_config.to_account_info();
+    let original_data_length = mint_config_account_info.data_len();
+    let mint_entries_start = original_data_length
+        .checked_sub((mint_config.supply as usize).checked_mul(mint_entry_size).expect("Mul error"))
+        .expect("Sub error");
+    let removed_start = mint_entries_start
+        .checked_add((start_index as usize).checked_mul(mint_entry_size).expect("Mul error"))
+        .expect("Add error");
+    let removed_end = mint_entries_start
+        .checked_add((end_index as usize).checked_mul(mint_entry_size).expect("Mul error"))
+        .expect("Add error");
+
+    let removed_weight = {
+        let mut mint_config_data = mint_config_account_info.data.borrow_mut();
+        let removed_weight = match mint_config.entry_storage {
+            MintEntryStorage::Template { .. } => {
+                // entries after the removed ones keep their template index once they move to a new position
+                for index in end_index as usize..mint_config.supply as usize {
+                    let position = mint_entries_start
+                        .checked_add(index.checked_mul(MINT_ENTRY_TEMPLATE_INDEX_SIZE).expect("Mul error"))
+                        .expect("Add error");
+                    let stored_index = u32::try_from(template_entry_index(&mint_config_data, position, index).checked_add(1).expect("Add error")).expect("Conversion error");
+                    mint_config_data[position..position + MINT_ENTRY_TEMPLATE_INDEX_SIZE].copy_from_slice(&stored_index.to_le_bytes());
+                }
+                end_index.checked_sub(start_index).expect("Sub error")
+            }
+            _ => {
+                let mut removed_weight: u64 = 0;
+                for entry_end_position in (removed_start..removed_end).step_by(MINT_ENTRY_SIZE).map(|p| p + MINT_ENTRY_SIZE) {
+                    let weight = u16::from_le_bytes(*array_ref![mint_config_data, entry_end_position - MINT_ENTRY_WEIGHT_LENGTH, MINT_ENTRY_WEIGHT_LENGTH]);
+                    removed_weight = removed_weight.checked_add(weight as u64).expect("Add error");
+                }
+                removed_weight
+            }
+        };
+        mint_config_data.copy_within(removed_end..original_data_length, removed_start);
+        removed_weight
+    };
+
+    // the mint config header keeps its size so entries still end at the end of the account after shrinking
+    mint_config.supply = mint_config.supply.checked_sub(end_index.checked_sub(start_index).expect("Sub error")).expect("Sub error");
+    mint_config.total_weight = mint_config.total_weight.checked_sub(removed_weight).expect("Sub error");
+    resize_account(&mint_config_account_info, mint_config.account_size(), collector, system_program)?;
+
+    // rows after the removed ones moved to other blocks
+    if mint_config.entry_storage == MintEntryStorage::Inline {
+        let weight_block_sums = {
+            let mint_config_data = mint_config_account_info.data.borrow();
+            weight_block_sums_of(
+                &mint_config_data,
+                mint_config_account_info.data_len().checked_sub(mint_config.mint_entries_length()).expect("Sub error"),
+                mint_config.supply,
+            )
+        };
+        set_weight_block_sums(mint_config, weight_block_sums, collector, system_program)?;
+    }
+    Ok(())
+}
+
+pub fn get_template_output_mint(mint_config: &mut Account<MintConfig>, template: &MintEntryTemplate, index: usize) -> Result<MintEntry> {
+    let remaining_tokens = usize::try_from(mint_config.remaining_tokens()).expect("Conversion error");
+    let mint_config_account_info = mint_config.to_account_info();
+    let mut mint_config_data = mint_config_account_info.data.borrow_mut();
+    let mint_entries_start = mint_config_account_info
+        .data_len()
+        .checked_sub(remaining_tokens.checked_mul(MINT_ENTRY_TEMPLATE_INDEX_SIZE).expect("Mul error"))
+        .expect("Sub error");
+
+    let position = mint_entries_start
+        .checked_add(index.checked_mul(MINT_ENTRY_TEMPLATE_INDEX_SIZE).expect("Mul error"))
+        .expect("Add error");
+    let entry_index = template_entry_index(&mint_config_data, position, template_unmoved_index(mint_config, index));
+    match mint_config.output_mint_config.selection_mode {
+        MintSelectionMode::Random => {
+            let last_index = remaining_tokens.checked_sub(1).expect("Sub error");
+            let last_position = mint_entries_start
+                .checked_add(last_index.checked_mul(MINT_ENTRY_TEMPLATE_INDEX_SIZE).expect("Mul error"))
+                .expect("Add error");
+            let last_entry_index = template_entry_index(&mint_config_data, last_position, template_unmoved_index(mint_config, last_index));
+
+            // move last entry up
+            let stored_index = u32::try_from(last_entry_index.checked_add(1).expect("Add error")).expect("Conversion error");
+            mint_config_data[position..position + MINT_ENTRY_TEMPLATE_INDEX_SIZE].copy_from_slice(&stored_index.to_le_bytes());
+        }
+        // the entry stays in place as a consumed row and the count moves past it
+        MintSelectionMode::Sequential => {}
+    }
     mint_config.count = mint_config.count.saturating_add(1);
+    mint_config.total_weight = mint_config.total_weight.saturating_sub(1);
+
+    Ok(template.render(entry_index.checked_add(template.index_offset).expect("Add error")))
+}
 
-    Ok(MintEntry { name, symbol, uri })
+// index of the entry at the `index`th remaining position if it never moved
+// random mode swaps the last entry into the picked position so the others keep theirs - sequential mode keeps consumed entries ahead of the remaining ones
+pub fn template_unmoved_index(mint_config: &MintConfig, index: usize) -> usize {
+    match mint_config.output_mint_config.selection_mode {
+        MintSelectionMode::Random => index,
+        MintSelectionMode::Sequential => index.checked_add(usize::try_from(mint_config.count).expect("Conversion error")).expect("Add error"),
+    }
+}
+
+// a stored value of 0 means the entry at this position has not moved and its index is `unmoved_index`
+pub fn template_entry_index(data: &RefMut<&mut [u8]>, position: usize, unmoved_index: usize) -> u64 {
+    let stored_index = u32::from_le_bytes(*array_ref![data, position, MINT_ENTRY_TEMPLATE_INDEX_SIZE]);
+    if stored_index == 0 {
+        unmoved_index as u64
+    } else {
+        stored_index.saturating_sub(1) as u64
+    }
 }
 
 pub fn get_slice_of_data(data: &RefMut<&mut [u8]>, start: usize, end: usize) -> Vec<u8> {
@@ -205,7 +871,7 @@ pub fn get_slice_of_data(data: &RefMut<&mut [u8]>, start: usize, end: usize) ->
 }
 
 pub fn reposition_mint_entries(mint_config: &Account<MintConfig>, original_data_length: usize, new_data_length: usize) -> Result<()> {
-    let mint_entries_length = (mint_config.remaining_tokens() as usize).checked_mul(MINT_ENTRY_SIZE).expect("Mul error");
+    let mint_entries_length = mint_config.mint_entries_length();
     let mint_config_data = mint_config.to_account_info().data;
     let mut mint_config_data_mut = mint_config_data.borrow_mut();
     mint_config_data_mut.copy_within(
@@ -215,6 +881,55 @@ pub fn reposition_mint_entries(mint_config: &Account<MintConfig>, original_data_
     Ok(())
 }
 
+// sha256 of the remaining mint entries in the order they are stored - pages are passed in order when using paged storage
+// NOTE: template and merkle storage also hash the entry storage as the stored data alone does not describe the entries
+pub fn mint_entries_hash<'info>(mint_config: &Account<'info, MintConfig>, remaining_accounts: &mut Iter<AccountInfo<'info>>) -> Result<[u8; 32]> {
+    if let MintEntryStorage::Paged { page_remaining } = &mint_config.entry_storage {
+        let page_count = u32::try_from(page_remaining.len()).expect("Conversion error");
+        let mut mint_entry_pages_data = Vec::with_capacity(page_remaining.len());
+        for page_index in 0..page_count {
+            let mint_entry_page_info = next_account_info(remaining_accounts)?;
+            let mint_entry_page = Account::<MintEntryPage>::try_from(mint_entry_page_info)?;
+            if mint_entry_page.mint_config != mint_config.key() || mint_entry_page.page_index != page_index {
+                return Err(error!(ErrorCode::InvalidMintEntryPage));
+            }
+            let mint_entries_start = mint_entry_page.remaining_entries_start(&mint_config.output_mint_config.selection_mode);
+            let mint_entries_end = mint_entries_start
+                .checked_add(mint_entry_page.remaining_entries().checked_mul(MINT_ENTRY_SIZE).expect("Mul error"))
+                .expect("Add error");
+            mint_entry_pages_data.push((mint_entry_page_info.data.borrow(), mint_entries_start, mint_entries_end));
+        }
+        return mint_entries_digest(
+            &mint_config.entry_storage,
+            &mint_entry_pages_data.iter().map(|(data, start, end)| &data[*start..*end]).collect::<Vec<&[u8]>>(),
+        );
+    }
+
+    // the remaining entries are stored last - consumed entries kept in sequential mode are not hashed
+    let mint_entries_length = match mint_config.entry_storage {
+        MintEntryStorage::Merkle { .. } => mint_config.mint_entries_length(),
+        _ => usize::try_from(mint_config.remaining_tokens())
+            .expect("Conversion error")
+            .checked_mul(mint_config.mint_entry_size())
+            .expect("Mul error"),
+    };
+    let mint_config_account_info = mint_config.to_account_info();
+    let mint_config_data = mint_config_account_info.data.borrow();
+    let mint_entries_start = mint_config_account_info.data_len().checked_sub(mint_entries_length).expect("Sub error");
+    mint_entries_digest(&mint_config.entry_storage, &[&mint_config_data[mint_entries_start..]])
+}
+
+pub fn mint_entries_digest(entry_storage: &MintEntryStorage, mint_entries_data: &[&[u8]]) -> Result<[u8; 32]> {
+    match entry_storage {
+        // the template strings and merkle root are hashed ahead of the index table or consumed bitmap
+        MintEntryStorage::Template { .. } | MintEntryStorage::Merkle { .. } => {
+            let entry_storage_data = entry_storage.try_to_vec()?;
+            Ok(hashv(&[&[entry_storage_data.as_slice()][..], mint_entries_data].concat()).to_bytes())
+        }
+        MintEntryStorage::Inline | MintEntryStorage::Paged { .. } => Ok(hashv(mint_entries_data).to_bytes()),
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct OutputMintConfig {
     // royalty amount of the output tokens
@@ -231,6 +946,107 @@ pub struct OutputMintConfig {
     pub merkle_tree: Option<Pubkey>,
     //  authority who must sign to release the tokens after mint
     pub release_authority: Option<Pubkey>,
+    // source of randomness used to pick the output mint entry
+    pub randomness_source: RandomnessSource,
+    // how the output mint entry is picked from the remaining entries
+    pub selection_mode: MintSelectionMode,
+    // mint output tokens with placeholder metadata and reveal the picked entry later with `reveal_output_mint`
+    pub hidden_settings: Option<HiddenSettings>,
+}
+
+impl OutputMintConfig {
+    pub fn check_valid(&self) -> Result<()> {
+        if self.creators.len() > MAX_CREATOR_LIMIT - 1 {
+            return err!(ErrorCode::TooManyCreators);
+        }
+        if self.merkle_tree.is_some() && (self.ruleset.is_some() || self.token_standard == TokenStandard::ProgrammableNonFungible) {
+            return err!(ErrorCode::ProgrammableAndMerkleTree);
+        }
+        if self.selection_mode == MintSelectionMode::Sequential && self.randomness_source != RandomnessSource::SlotHashes {
+            return err!(ErrorCode::SequentialAndRandomnessSource);
+        }
+        if let Some(hidden_settings) = &self.hidden_settings {
+            // compressed tokens cannot be updated after mint
+            if self.merkle_tree.is_some() {
+                return err!(ErrorCode::HiddenAndMerkleTree);
+            }
+            if hidden_settings.name.len() > MAX_NAME_LENGTH - 4 || hidden_settings.uri.len() > MAX_URI_LENGTH - 4 {
+                return err!(ErrorCode::InvalidHiddenSettings);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Eq, PartialEq, Debug)]
+pub struct HiddenSettings {
+    // placeholder name and uri set on the output tokens until they are revealed
+    pub name: String,
+    pub uri: String,
+    // authority who must sign to reveal (NOTE: anyone can reveal when not set)
+    pub reveal_authority: Option<Pubkey>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Eq, PartialEq, Debug)]
+pub enum MintPhaseSelection {
+    First = 0,    // pick the first phase in phase order
+    Cheapest = 1, // pick the phase with the lowest transfer amount (NOTE: amounts are compared as is regardless of the token transferred)
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Eq, PartialEq, Debug)]
+pub enum MintSelectionMode {
+    Random = 0,     // pick a random remaining entry
+    Sequential = 1, // pick entries in the order they were set
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Eq, PartialEq, Debug)]
+pub enum RandomnessSource {
+    SlotHashes,                     // pick the entry in the mint transaction using the most recent slot hash
+    CommitReveal,                   // mint commits to the current slot and `reveal_mint` picks the entry using that slot's hash in a later slot
+    Vrf { oracle_program: Pubkey }, // mint requests randomness from the oracle program and `reveal_mint` picks the entry once it is fulfilled
+}
+
+// Interface an oracle program must implement to be used with `RandomnessSource::Vrf`
+// (see `programs/mock-vrf-oracle` for the mock oracle the integration tests run against)
+// 1. `mint` invokes the oracle with data `VRF_REQUEST_RANDOMNESS_DISCRIMINATOR ++ seed` and accounts
+//    `[vrf_randomness (mut), requester (signer), payer (mut, signer), system_program]` where the requester is the pending mint
+// 2. the oracle writes a `VrfRandomness` account for the request and sets `fulfilled` once the randomness is written
+// 3. the oracle then invokes `vrf_callback` with accounts `[vrf_randomness (signer), requester (mut), mint_config]`
+//    and the randomness account must sign so only the oracle can fulfill the pending mint
+//    the randomness is copied into the pending mint so the oracle may close the randomness account once the callback returns
+// NOTE: discriminators are the anchor sighashes of `global:request_randomness` and `account:VrfRandomness` so anchor oracles can implement it directly
+pub const VRF_REQUEST_RANDOMNESS_DISCRIMINATOR: [u8; 8] = [213, 5, 173, 166, 37, 236, 31, 18];
+pub const VRF_RANDOMNESS_DISCRIMINATOR: [u8; 8] = [56, 229, 147, 87, 235, 27, 65, 129];
+pub const VRF_RANDOMNESS_SIZE: usize = 8 + 32 + 32 + 1 + 32;
+// randomness account written by the oracle program
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct VrfRandomness {
+    // must equal VRF_RANDOMNESS_DISCRIMINATOR
+    pub discriminator: [u8; 8],
+    // account that requested the randomness (the pending mint PDA)
+    pub requester: Pubkey,
+    // seed provided with the request (the pending mint commit hash)
+    pub seed: [u8; 32],
+    // set by the oracle once `randomness` has been written
+    pub fulfilled: bool,
+    pub randomness: [u8; 32],
+}
+
+impl VrfRandomness {
+    pub fn load(account_info: &AccountInfo, oracle_program: &Pubkey) -> Result<VrfRandomness> {
+        if account_info.owner != oracle_program {
+            return Err(error!(ErrorCode::InvalidVrfRandomness));
+        }
+        let data = account_info.try_borrow_data()?;
+        if data.len() < VRF_RANDOMNESS_SIZE {
+            return Err(error!(ErrorCode::InvalidVrfRandomness));
+        }
+        let vrf_randomness = VrfRandomness::deserialize(&mut &data[..VRF_RANDOMNESS_SIZE]).map_err(|_| error!(ErrorCode::InvalidVrfRandomness))?;
+        if vrf_randomness.discriminator != VRF_RANDOMNESS_DISCRIMINATOR {
+            return Err(error!(ErrorCode::InvalidVrfRandomness));
+        }
+        Ok(vrf_randomness)
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Eq, PartialEq, Debug)]
@@ -273,36 +1089,187 @@ impl TokenStandard {
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
 pub struct MintPhase {
     // if set - condition must be valid to mint from this phase
-    pub start_condition: Option<MintPhaseStartEndCondition>,
+    pub start_condition: Option<MintPhaseCondition>,
     // if set - condition must be invalid to mint from this phase
-    pub end_condition: Option<MintPhaseStartEndCondition>,
+    pub end_condition: Option<MintPhaseCondition>,
     // token checks to validate when minting
     pub token_checks: Vec<MintPhaseTokenCheck>,
     // authorization check to valid via authorization record PDA when minting
     pub authorization: Option<MintPhaseAuthorizationCheck>,
     // JSON formatted metadata string
     pub metadata: String,
+    // if set - price charged by the transfer token check instead of its fixed amount
+    pub price_curve: Option<MintPhasePriceCurve>,
+    // if set - max tokens each user can mint from this phase tracked with a `MintCounter` PDA (NOTE: the user must sign)
+    pub max_per_wallet: Option<u64>,
+    // if set - max tokens that can be minted from this phase
+    pub max_supply: Option<u64>,
+    // count of tokens minted from this phase (NOTE: managed by the program and kept by index when phases are updated)
+    pub minted: u64,
+    // if set - parts of the output mint config to replace for tokens minted from this phase
+    pub output_overrides: Option<MintPhaseOutputOverrides>,
+    // if set - seconds each user must wait between mints from this phase tracked with a `MintCooldown` PDA (NOTE: must be positive and the user must sign)
+    pub cooldown_seconds: Option<i64>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
-pub struct MintPhaseStartEndCondition {
-    // start or end timestamp in seconds
-    pub time_seconds: Option<i64>,
-    // start or end count
-    pub count: Option<u64>,
+pub struct MintPhaseOutputOverrides {
+    // each set field replaces the matching `OutputMintConfig` field (NOTE: unset fields keep the mint config value)
+    pub collection: Option<Pubkey>,
+    pub creators: Option<Vec<Creator>>,
+    pub seller_fee_basis_points: Option<u16>,
+    pub token_standard: Option<TokenStandard>,
+    pub ruleset: Option<Pubkey>,
+    pub release_authority: Option<Pubkey>,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
-pub struct MintPhaseTokenCheck {
-    // address kind for this token check
-    pub address_kind: MintPhaseTokenCheckAddressKind,
-    // address for this token check (Pubkey::default() for native SOL)
-    pub address: Pubkey,
-    // amount of tokens to use for this token check
-    pub amount: u64,
-    // transfer target if mode is transfer
-    pub transfer_target: Option<Pubkey>,
-    // mode for this token check
+impl MintPhase {
+    pub fn check_valid(&self) -> Result<()> {
+        if let Some(start_condition) = &self.start_condition {
+            start_condition.check_valid()?;
+        }
+        if let Some(end_condition) = &self.end_condition {
+            end_condition.check_valid()?;
+        }
+        if let Some(price_curve) = &self.price_curve {
+            // the curve prices exactly one payment
+            if self.token_checks.iter().filter(|c| c.mode == MintPhaseTokenCheckMode::Transfer).count() != 1 {
+                return err!(ErrorCode::InvalidPriceCurve);
+            }
+            price_curve.check_valid()?;
+        }
+        if let Some(cooldown_seconds) = self.cooldown_seconds {
+            if cooldown_seconds <= 0 {
+                return err!(ErrorCode::InvalidMintCooldown);
+            }
+        }
+        Ok(())
+    }
+
+    // checks are applied in order and the first failing one is returned
+    // 1. start condition not met -> `PhaseNotStarted` (skipped for the mint config authority)
+    // 2. end condition met -> `PhaseEnded` (applies to the authority too)
+    // 3. max supply minted -> `PhaseSoldOut`
+    pub fn check_active(&self, context: &ConditionContext, is_authority: bool) -> MintValidation<()> {
+        if let Some(start_condition) = &self.start_condition {
+            if !start_condition.evaluate(context) && !is_authority {
+                return MintValidation::Invalid(ErrorCode::PhaseNotStarted);
+            }
+        }
+        if let Some(end_condition) = &self.end_condition {
+            if end_condition.evaluate(context) {
+                return MintValidation::Invalid(ErrorCode::PhaseEnded);
+            }
+        }
+        if let Some(max_supply) = self.max_supply {
+            if self.minted >= max_supply {
+                return MintValidation::Invalid(ErrorCode::PhaseSoldOut);
+            }
+        }
+        MintValidation::Valid(())
+    }
+
+    // total amount transferred by the token checks at the given time and mint count
+    pub fn transfer_amount(&self, timestamp: i64, count: u64) -> u64 {
+        self.token_checks
+            .iter()
+            .filter(|c| c.mode == MintPhaseTokenCheckMode::Transfer)
+            .fold(0, |total, c| total.saturating_add(self.token_check_amount(c, timestamp, count)))
+    }
+
+    // amount to use for the token check at the given time and mint count
+    pub fn token_check_amount(&self, token_check: &MintPhaseTokenCheck, timestamp: i64, count: u64) -> u64 {
+        match (&token_check.mode, &self.price_curve) {
+            (MintPhaseTokenCheckMode::Transfer, Some(price_curve)) => price_curve.price(timestamp, count),
+            _ => token_check.amount,
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum MintPhasePriceCurve {
+    // price starts at `start_price` and drops by `decay_per_interval` every `interval_seconds` after `start_seconds` until it reaches `floor_price`
+    DutchAuction {
+        start_seconds: i64,
+        start_price: u64,
+        floor_price: u64,
+        decay_per_interval: u64,
+        interval_seconds: i64,
+    },
+    // price increases by `increment_per_mint` for every token minted from the mint config
+    Linear {
+        start_price: u64,
+        increment_per_mint: u64,
+    },
+    // price of the first tier whose `up_to_count` is above the mint count - the last tier applies once every tier is passed
+    Tiers {
+        tiers: Vec<MintPhasePriceTier>,
+    },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MintPhasePriceTier {
+    // mint count this tier applies below
+    pub up_to_count: u64,
+    pub price: u64,
+}
+
+impl MintPhasePriceCurve {
+    pub fn check_valid(&self) -> Result<()> {
+        match self {
+            MintPhasePriceCurve::DutchAuction {
+                start_price,
+                floor_price,
+                interval_seconds,
+                ..
+            } => {
+                if *interval_seconds <= 0 || floor_price > start_price {
+                    return err!(ErrorCode::InvalidPriceCurve);
+                }
+            }
+            MintPhasePriceCurve::Linear { .. } => {}
+            MintPhasePriceCurve::Tiers { tiers } => {
+                if tiers.is_empty() || tiers.windows(2).any(|w| w[0].up_to_count >= w[1].up_to_count) {
+                    return err!(ErrorCode::InvalidPriceCurve);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // `count` is the number of tokens already claimed from the mint config
+    pub fn price(&self, timestamp: i64, count: u64) -> u64 {
+        match self {
+            MintPhasePriceCurve::DutchAuction {
+                start_seconds,
+                start_price,
+                floor_price,
+                decay_per_interval,
+                interval_seconds,
+            } => {
+                let intervals = u64::try_from(timestamp.saturating_sub(*start_seconds).max(0) / interval_seconds).expect("Conversion error");
+                cmp::max(start_price.saturating_sub(intervals.saturating_mul(*decay_per_interval)), *floor_price)
+            }
+            MintPhasePriceCurve::Linear { start_price, increment_per_mint } => start_price.saturating_add(count.saturating_mul(*increment_per_mint)),
+            MintPhasePriceCurve::Tiers { tiers } => match tiers.iter().find(|t| count < t.up_to_count) {
+                Some(tier) => tier.price,
+                None => tiers.last().expect("Tiers are not empty").price,
+            },
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MintPhaseTokenCheck {
+    // address kind for this token check
+    pub address_kind: MintPhaseTokenCheckAddressKind,
+    // address for this token check (Pubkey::default() for native SOL)
+    pub address: Pubkey,
+    // amount of tokens to use for this token check
+    pub amount: u64,
+    // transfer target if mode is transfer
+    pub transfer_target: Option<Pubkey>,
+    // mode for this token check
     pub mode: MintPhaseTokenCheckMode,
 }
 
@@ -313,7 +1280,7 @@ pub enum MintPhaseTokenCheckAddressKind {
     Creator = 2,    // the address is a creator address
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
 pub enum MintPhaseTokenCheckMode {
     Check = 0,    // check that the tokens are owned
     Transfer = 1, // transfer the specified tokens
@@ -334,12 +1301,118 @@ pub enum MintPhaseAuthorizationMode {
 pub const MAX_NAME_LENGTH: usize = 32;
 pub const MAX_SYMBOL_LENGTH: usize = 10;
 pub const MAX_URI_LENGTH: usize = 200;
-pub const MINT_ENTRY_SIZE: usize = MAX_NAME_LENGTH + MAX_SYMBOL_LENGTH + MAX_URI_LENGTH;
-#[derive(AnchorSerialize, AnchorDeserialize, Debug)]
+pub const MINT_ENTRY_WEIGHT_LENGTH: usize = 2;
+// rows per weight block sum - a weighted pick reads every block sum and the rows of one block
+pub const MINT_ENTRY_WEIGHT_BLOCK_SIZE: usize = 128;
+pub const MINT_ENTRY_TEMPLATE_INDEX_SIZE: usize = 4;
+pub const MINT_ENTRY_SIZE: usize = MAX_NAME_LENGTH + MAX_SYMBOL_LENGTH + MAX_URI_LENGTH + MINT_ENTRY_WEIGHT_LENGTH;
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct MintEntry {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    // relative chance of this entry being picked
+    pub weight: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MerkleMintEntry {
+    // index of the entry in the merkle tree
+    pub index: u64,
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+    pub proof: Vec<[u8; 32]>,
+}
+
+// each field is stored in a fixed width slot of a 4 byte length prefix followed by the NUL padded string
+impl MintEntry {
+    pub fn encode(&self) -> Result<Vec<u8>> {
+        let mut data = Vec::with_capacity(MINT_ENTRY_SIZE);
+        encode_fixed_width_str(&self.name, MAX_NAME_LENGTH, &mut data)?;
+        encode_fixed_width_str(&self.symbol, MAX_SYMBOL_LENGTH, &mut data)?;
+        encode_fixed_width_str(&self.uri, MAX_URI_LENGTH, &mut data)?;
+        data.extend_from_slice(&self.weight.to_le_bytes());
+        Ok(data)
+    }
+
+    pub fn decode(data: &[u8]) -> Result<MintEntry> {
+        if data.len() != MINT_ENTRY_SIZE {
+            return Err(error!(ErrorCode::InvalidMintEntryData));
+        }
+        let (name_data, data) = data.split_at(MAX_NAME_LENGTH);
+        let (symbol_data, data) = data.split_at(MAX_SYMBOL_LENGTH);
+        let (uri_data, weight_data) = data.split_at(MAX_URI_LENGTH);
+        Ok(MintEntry {
+            name: decode_fixed_width_str(name_data)?,
+            symbol: decode_fixed_width_str(symbol_data)?,
+            uri: decode_fixed_width_str(uri_data)?,
+            weight: u16::from_le_bytes(*array_ref![weight_data, 0, MINT_ENTRY_WEIGHT_LENGTH]),
+        })
+    }
+}
+
+fn encode_fixed_width_str(value: &str, width: usize, data: &mut Vec<u8>) -> Result<()> {
+    let max_length = width.checked_sub(4).expect("Sub error");
+    if value.len() > max_length {
+        return Err(error!(ErrorCode::MintEntryFieldTooLong));
+    }
+    data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+    data.extend_from_slice(value.as_bytes());
+    data.resize(data.len() + max_length - value.len(), 0);
+    Ok(())
+}
+
+fn decode_fixed_width_str(data: &[u8]) -> Result<String> {
+    let length = u32::from_le_bytes(*array_ref![data, 0, 4]) as usize;
+    let value = data[4..].get(..length).ok_or(ErrorCode::InvalidMintEntryData)?;
+    // entries set before the length prefix was exact are padded with NULs up to the full width
+    let value = match value.iter().rposition(|b| *b != 0) {
+        Some(end) => &value[..=end],
+        None => &value[..0],
+    };
+    Ok(std::str::from_utf8(value).map_err(|_| error!(ErrorCode::InvalidMintEntryData))?.to_string())
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum MintEntryStorage {
+    Inline,                                   // full mint entries are stored in the mint config
+    Template { template: MintEntryTemplate }, // only entry indices are stored and each entry is rendered from the template
+    Paged { page_remaining: Vec<u32> },       // full mint entries are stored in `MintEntryPage` accounts and the remaining entries of each page are kept by page index
+    Merkle { root: [u8; 32] },                // only a bitmap of consumed indices is stored and the client supplies each entry with its proof
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub struct MintEntryTemplate {
+    // name of each entry is `name_prefix` followed by the entry number (eg. "Foo #")
+    pub name_prefix: String,
+    // symbol of every entry
+    pub symbol: String,
+    // uri of each entry is `uri_prefix` + entry number + `uri_suffix` (eg. "https://host/" and ".json")
+    pub uri_prefix: String,
+    pub uri_suffix: String,
+    // added to the entry index to get the entry number
+    pub index_offset: u64,
+}
+
+impl MintEntryTemplate {
+    pub fn render(&self, number: u64) -> MintEntry {
+        MintEntry {
+            name: format!("{}{}", self.name_prefix, number),
+            symbol: self.symbol.clone(),
+            uri: format!("{}{}{}", self.uri_prefix, number, self.uri_suffix),
+            weight: 1,
+        }
+    }
+
+    pub fn check_valid(&self, supply: u64) -> Result<()> {
+        let last_entry = self.render(supply.saturating_sub(1).checked_add(self.index_offset).expect("Add error"));
+        // same limits as inline entries which keep room for the length prefix
+        if last_entry.name.len() > MAX_NAME_LENGTH - 4 || last_entry.symbol.len() > MAX_SYMBOL_LENGTH - 4 || last_entry.uri.len() > MAX_URI_LENGTH - 4 {
+            return Err(error!(ErrorCode::InvalidMintEntryTemplate));
+        }
+        Ok(())
+    }
 }
 
 pub const MINT_PHASE_AUTHORIZATION_PREFIX: &str = "authorization";
@@ -372,6 +1445,8 @@ impl MintPhaseAuthorization {
     }
 }
 
+// size of pending releases created before `release_authority` was added
+pub const OUTPUT_MINT_PENDING_RELEASE_LEGACY_SIZE: usize = 8 + 1 + 32 + 32 + 32;
 #[account]
 #[derive(Default, Debug)]
 pub struct OutputMintPendingRelease {
@@ -379,6 +1454,8 @@ pub struct OutputMintPendingRelease {
     pub mint_config: Pubkey,
     pub mint: Pubkey,
     pub holder: Pubkey,
+    // authority who must sign to release - resolved from the phase the token was minted from
+    pub release_authority: Pubkey,
 }
 
 impl OutputMintPendingRelease {
@@ -390,6 +1467,33 @@ impl OutputMintPendingRelease {
         return 8 + std::mem::size_of::<OutputMintPendingRelease>();
     }
 
+    // loads either account layout and returns the authority who must sign to release
+    // pending releases created before `release_authority` was stored fall back to the mint config release authority (NOTE: anyone can release when it is not set)
+    pub fn load(account_info: &AccountInfo, mint_config: &MintConfig) -> Result<(OutputMintPendingRelease, Option<Pubkey>)> {
+        if account_info.owner != &crate::id() {
+            return Err(error!(ErrorCode::InvalidOutputMintsPendingRelease));
+        }
+        let data = account_info.try_borrow_data()?;
+        if data.len() >= OutputMintPendingRelease::default_size() {
+            let output_mint_pending_release = OutputMintPendingRelease::try_deserialize(&mut data.as_ref())?;
+            let release_authority = output_mint_pending_release.release_authority;
+            return Ok((output_mint_pending_release, Some(release_authority)));
+        }
+        if data.len() < OUTPUT_MINT_PENDING_RELEASE_LEGACY_SIZE || data[..8] != OutputMintPendingRelease::discriminator() {
+            return Err(error!(ErrorCode::InvalidOutputMintsPendingRelease));
+        }
+        let mut legacy_data = &data[8..];
+        let release_authority = mint_config.output_mint_config.release_authority;
+        let output_mint_pending_release = OutputMintPendingRelease {
+            bump: u8::deserialize(&mut legacy_data)?,
+            mint_config: Pubkey::deserialize(&mut legacy_data)?,
+            mint: Pubkey::deserialize(&mut legacy_data)?,
+            holder: Pubkey::deserialize(&mut legacy_data)?,
+            release_authority: release_authority.unwrap_or_default(),
+        };
+        Ok((output_mint_pending_release, release_authority))
+    }
+
     pub fn seeds(mint_config: &Pubkey, mint_id: &Pubkey, expected_key: &Pubkey) -> Result<(Vec<Vec<u8>>, u8)> {
         let mut seeds = vec![
             OutputMintPendingRelease::seed_prefix().as_bytes().as_ref().to_vec(),
@@ -404,3 +1508,927 @@ impl OutputMintPendingRelease {
         Ok((seeds, bump))
     }
 }
+
+pub const MINT_COUNTER_PREFIX: &str = "mint-counter";
+pub const MINT_COUNTER_SIZE: usize = 8 + std::mem::size_of::<MintCounter>();
+#[account]
+#[derive(Default, Debug)]
+pub struct MintCounter {
+    pub bump: u8,
+    pub mint_config: Pubkey,
+    pub mint_phase_index: u8,
+    pub user: Pubkey,
+    pub count: u64,
+}
+
+impl MintCounter {
+    pub fn seeds(mint_config: &Pubkey, mint_phase_ix: u8, user: &Pubkey, expected_key: &Pubkey) -> Result<(Vec<Vec<u8>>, u8)> {
+        let mut seeds = vec![
+            MINT_COUNTER_PREFIX.as_bytes().as_ref().to_vec(),
+            mint_config.as_ref().to_vec(),
+            mint_phase_ix.to_le_bytes().to_vec(),
+            user.as_ref().to_vec(),
+        ];
+        let (key, bump) = Pubkey::find_program_address(&seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>(), &crate::id());
+        if &key != expected_key {
+            return Err(error!(ErrorCode::InvalidMintCounter));
+        }
+        seeds.push(vec![bump]);
+        Ok((seeds, bump))
+    }
+}
+
+pub const MINT_COOLDOWN_PREFIX: &str = "mint-cooldown";
+pub const MINT_COOLDOWN_SIZE: usize = 8 + std::mem::size_of::<MintCooldown>();
+#[account]
+#[derive(Default, Debug)]
+pub struct MintCooldown {
+    pub bump: u8,
+    pub mint_config: Pubkey,
+    pub mint_phase_index: u8,
+    pub user: Pubkey,
+    // timestamp of the users last mint from the phase
+    pub last_mint_seconds: i64,
+}
+
+impl MintCooldown {
+    pub fn seeds(mint_config: &Pubkey, mint_phase_ix: u8, user: &Pubkey, expected_key: &Pubkey) -> Result<(Vec<Vec<u8>>, u8)> {
+        let mut seeds = vec![
+            MINT_COOLDOWN_PREFIX.as_bytes().as_ref().to_vec(),
+            mint_config.as_ref().to_vec(),
+            mint_phase_ix.to_le_bytes().to_vec(),
+            user.as_ref().to_vec(),
+        ];
+        let (key, bump) = Pubkey::find_program_address(&seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>(), &crate::id());
+        if &key != expected_key {
+            return Err(error!(ErrorCode::InvalidMintCooldown));
+        }
+        seeds.push(vec![bump]);
+        Ok((seeds, bump))
+    }
+
+    pub fn is_active(&self, cooldown_seconds: i64, timestamp: i64) -> bool {
+        timestamp.saturating_sub(self.last_mint_seconds) < cooldown_seconds
+    }
+}
+
+pub const MINT_WALLET_COUNTER_PREFIX: &str = "mint-wallet-counter";
+pub const MINT_WALLET_COUNTER_SIZE: usize = 8 + std::mem::size_of::<MintWalletCounter>();
+#[account]
+#[derive(Default, Debug)]
+pub struct MintWalletCounter {
+    pub bump: u8,
+    pub mint_config: Pubkey,
+    pub user: Pubkey,
+    pub count: u64,
+}
+
+impl MintWalletCounter {
+    pub fn seeds(mint_config: &Pubkey, user: &Pubkey, expected_key: &Pubkey) -> Result<(Vec<Vec<u8>>, u8)> {
+        let mut seeds = vec![MINT_WALLET_COUNTER_PREFIX.as_bytes().as_ref().to_vec(), mint_config.as_ref().to_vec(), user.as_ref().to_vec()];
+        let (key, bump) = Pubkey::find_program_address(&seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>(), &crate::id());
+        if &key != expected_key {
+            return Err(error!(ErrorCode::InvalidMintCounter));
+        }
+        seeds.push(vec![bump]);
+        Ok((seeds, bump))
+    }
+}
+
+pub const PENDING_MINT_PREFIX: &str = "pending-mint";
+pub const PENDING_MINT_SIZE: usize = 8 + std::mem::size_of::<PendingMint>();
+// slots kept by the slot hashes sysvar - vrf commits the oracle has not fulfilled expire after the same number of slots
+pub const PENDING_MINT_EXPIRY_SLOTS: u64 = 512;
+// slots the payer has to reveal in once the randomness is known - later reveals are forfeited so the pick cannot be timed
+pub const PENDING_MINT_REVEAL_SLOTS: u64 = 32;
+#[account]
+#[derive(Default, Debug)]
+pub struct PendingMint {
+    pub bump: u8,
+    pub mint_config: Pubkey,
+    pub user: Pubkey,
+    // payer of the commit - only the payer can reveal and receives the rent back from the reveal
+    pub payer: Pubkey,
+    pub mint_phase_index: u8,
+    // slot the mint was committed in - its slot hash does not exist until the next slot
+    pub commit_slot: u64,
+    pub commit_hash: [u8; 32],
+    // randomness account requested from the oracle when using `RandomnessSource::Vrf`
+    pub vrf_randomness: Option<Pubkey>,
+    // set by `vrf_callback` once the oracle fulfills the request
+    pub randomness: Option<[u8; 32]>,
+    // first slot the randomness is known in - the slot after the commit or the slot of `vrf_callback`
+    pub reveal_slot: u64,
+}
+
+impl PendingMint {
+    pub fn seeds(mint_config: &Pubkey, user: &Pubkey, expected_key: &Pubkey) -> Result<(Vec<Vec<u8>>, u8)> {
+        let mut seeds = vec![PENDING_MINT_PREFIX.as_bytes().as_ref().to_vec(), mint_config.key().as_ref().to_vec(), user.as_ref().to_vec()];
+        let (key, bump) = Pubkey::find_program_address(&seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>(), &crate::id());
+        if &key != expected_key {
+            return Err(error!(ErrorCode::InvalidPendingMint));
+        }
+        seeds.push(vec![bump]);
+        Ok((seeds, bump))
+    }
+
+    // a commit expires once its reveal window has passed so the payer cannot wait for a better pick
+    pub fn is_expired(&self, slot: u64) -> bool {
+        match (self.vrf_randomness, self.randomness) {
+            // the oracle has not fulfilled the request
+            (Some(_), None) => slot > self.commit_slot.saturating_add(PENDING_MINT_EXPIRY_SLOTS),
+            _ => slot > self.reveal_slot.saturating_add(PENDING_MINT_REVEAL_SLOTS),
+        }
+    }
+}
+
+pub const MINT_ENTRY_PAGE_PREFIX: &str = "mint-entry-page";
+pub const MINT_ENTRY_PAGE_CAPACITY: u32 = 1000;
+// size of the page header - entries are stored after it
+pub const MINT_ENTRY_PAGE_HEADER_SIZE: usize = 8 + std::mem::size_of::<MintEntryPage>();
+#[account]
+#[derive(Default, Debug)]
+pub struct MintEntryPage {
+    pub bump: u8,
+    pub mint_config: Pubkey,
+    pub page_index: u32,
+    // entries set in this page - entry `i` of the page is global entry `page_index * MINT_ENTRY_PAGE_CAPACITY + i`
+    pub supply: u32,
+    // entries minted from this page
+    pub count: u32,
+}
+
+impl MintEntryPage {
+    pub fn remaining_entries(&self) -> usize {
+        self.supply.checked_sub(self.count).expect("Sub error") as usize
+    }
+
+    // position of the first remaining entry - entries minted in sequential mode stay ahead of it so the next entry never has to be moved
+    pub fn remaining_entries_start(&self, selection_mode: &MintSelectionMode) -> usize {
+        match selection_mode {
+            MintSelectionMode::Random => MINT_ENTRY_PAGE_HEADER_SIZE,
+            MintSelectionMode::Sequential => MINT_ENTRY_PAGE_HEADER_SIZE
+                .checked_add((self.count as usize).checked_mul(MINT_ENTRY_SIZE).expect("Mul error"))
+                .expect("Add error"),
+        }
+    }
+
+    pub fn seeds(mint_config: &Pubkey, page_index: u32, expected_key: &Pubkey) -> Result<Vec<Vec<u8>>> {
+        let mut seeds = vec![MINT_ENTRY_PAGE_PREFIX.as_bytes().as_ref().to_vec(), mint_config.as_ref().to_vec(), page_index.to_le_bytes().to_vec()];
+        let (key, bump) = Pubkey::find_program_address(&seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>(), &crate::id());
+        if &key != expected_key {
+            return Err(error!(ErrorCode::InvalidMintEntryPage));
+        }
+        seeds.push(vec![bump]);
+        Ok(seeds)
+    }
+}
+
+pub const HIDDEN_MINT_PREFIX: &str = "hidden-mint";
+pub const HIDDEN_MINT_SIZE: usize = 8 + 1 + 32 + 32 + 32 + MINT_ENTRY_SIZE + 1;
+#[account]
+#[derive(Debug)]
+pub struct HiddenMint {
+    pub bump: u8,
+    pub mint_config: Pubkey,
+    pub mint: Pubkey,
+    // payer of the mint - receives the rent back on reveal
+    pub payer: Pubkey,
+    // entry picked at mint - entries are removed from the mint config once picked so the full entry is kept
+    pub mint_entry: MintEntry,
+    // phase the token was minted from - its output overrides apply on reveal
+    pub mint_phase_index: u8,
+}
+
+impl HiddenMint {
+    pub fn seeds(mint_config: &Pubkey, mint: &Pubkey, expected_key: &Pubkey) -> Result<(Vec<Vec<u8>>, u8)> {
+        let mut seeds = vec![HIDDEN_MINT_PREFIX.as_bytes().as_ref().to_vec(), mint_config.as_ref().to_vec(), mint.as_ref().to_vec()];
+        let (key, bump) = Pubkey::find_program_address(&seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>(), &crate::id());
+        if &key != expected_key {
+            return Err(error!(ErrorCode::InvalidHiddenMint));
+        }
+        seeds.push(vec![bump]);
+        Ok((seeds, bump))
+    }
+}
+
+// layout of mint configs created before versioned fields were added - migrated with `migrate_mint_config`
+pub const MINT_ENTRY_V0_SIZE: usize = MAX_NAME_LENGTH + MAX_SYMBOL_LENGTH + MAX_URI_LENGTH;
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MintConfigV0 {
+    pub bump: u8,
+    pub authority: Pubkey,
+    pub name: String,
+    pub supply: u64,
+    pub count: u64,
+    pub output_mint_config: OutputMintConfigV0,
+    pub mint_phases: Vec<MintPhaseV0>,
+    pub metadata: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct OutputMintConfigV0 {
+    pub seller_fee_basis_points: u16,
+    pub token_standard: TokenStandard,
+    pub collection: Option<Pubkey>,
+    pub ruleset: Option<Pubkey>,
+    pub creators: Vec<Creator>,
+    pub merkle_tree: Option<Pubkey>,
+    pub release_authority: Option<Pubkey>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MintPhaseV0 {
+    pub start_condition: Option<MintPhaseStartEndConditionV0>,
+    pub end_condition: Option<MintPhaseStartEndConditionV0>,
+    pub token_checks: Vec<MintPhaseTokenCheck>,
+    pub authorization: Option<MintPhaseAuthorizationCheck>,
+    pub metadata: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct MintPhaseStartEndConditionV0 {
+    pub time_seconds: Option<i64>,
+    pub count: Option<u64>,
+}
+
+impl MintPhaseStartEndConditionV0 {
+    // met once any of the set fields is reached (NOTE: never met when none are set)
+    pub fn condition(&self) -> MintPhaseCondition {
+        let mut conditions = Vec::new();
+        if let Some(seconds) = self.time_seconds {
+            conditions.push(MintPhaseCondition::Time { seconds });
+        }
+        if let Some(count) = self.count {
+            conditions.push(MintPhaseCondition::Count { count });
+        }
+        MintPhaseCondition::Any { conditions }
+    }
+}
+
+impl MintConfigV0 {
+    // every entry had a weight of 1 and new fields keep the behavior of the legacy program
+    pub fn migrate(&self) -> MintConfig {
+        MintConfig {
+            bump: self.bump,
+            authority: self.authority,
+            name: self.name.clone(),
+            supply: self.supply,
+            count: self.count,
+            output_mint_config: OutputMintConfig {
+                seller_fee_basis_points: self.output_mint_config.seller_fee_basis_points,
+                token_standard: self.output_mint_config.token_standard.clone(),
+                collection: self.output_mint_config.collection,
+                ruleset: self.output_mint_config.ruleset,
+                creators: self.output_mint_config.creators.clone(),
+                merkle_tree: self.output_mint_config.merkle_tree,
+                release_authority: self.output_mint_config.release_authority,
+                randomness_source: RandomnessSource::SlotHashes,
+                selection_mode: MintSelectionMode::Random,
+                hidden_settings: None,
+            },
+            mint_phases: self
+                .mint_phases
+                .iter()
+                .map(|mint_phase| MintPhase {
+                    start_condition: mint_phase.start_condition.as_ref().map(|c| c.condition()),
+                    end_condition: mint_phase.end_condition.as_ref().map(|c| c.condition()),
+                    token_checks: mint_phase.token_checks.clone(),
+                    authorization: mint_phase.authorization.clone(),
+                    metadata: mint_phase.metadata.clone(),
+                    ..MintPhase::default()
+                })
+                .collect(),
+            metadata: self.metadata.clone(),
+            pending_reveals: 0,
+            total_weight: self.supply.checked_sub(self.count).expect("Sub error"),
+            provenance_hash: None,
+            entry_storage: MintEntryStorage::Inline,
+            max_per_wallet_total: None,
+            paused: false,
+            pause_authority: None,
+            phase_selection: MintPhaseSelection::First,
+            bot_tax_lamports: None,
+            weight_block_sums: unit_weight_block_sums(self.supply.checked_sub(self.count).expect("Sub error")),
+        }
+    }
+}
+
+// moves `remaining_entries` legacy rows from `legacy_start` to `new_start` and appends a weight of 1 to each
+// NOTE: the new start must not be before the legacy start - rows are moved last to first so none are overwritten before they are moved
+pub fn migrate_mint_entries(data: &mut [u8], legacy_start: usize, new_start: usize, remaining_entries: usize) {
+    for index in (0..remaining_entries).rev() {
+        let legacy_position = legacy_start.checked_add(index.checked_mul(MINT_ENTRY_V0_SIZE).expect("Mul error")).expect("Add error");
+        let new_position = new_start.checked_add(index.checked_mul(MINT_ENTRY_SIZE).expect("Mul error")).expect("Add error");
+        data.copy_within(legacy_position..legacy_position + MINT_ENTRY_V0_SIZE, new_position);
+        data[new_position + MINT_ENTRY_V0_SIZE..new_position + MINT_ENTRY_SIZE].copy_from_slice(&1u16.to_le_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::hash::hash;
+
+    fn error_code<T: std::fmt::Debug>(result: Result<T>) -> u32 {
+        match result {
+            Err(anchor_lang::error::Error::AnchorError(error)) => error.error_code_number,
+            other => panic!("Expected anchor error, got {:?}", other),
+        }
+    }
+
+    fn mint_entry(name: &str, symbol: &str, uri: &str) -> MintEntry {
+        MintEntry {
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            uri: uri.to_string(),
+            weight: 3,
+        }
+    }
+
+    #[test]
+    fn test_mint_entry_round_trip() {
+        for entry in [
+            mint_entry("", "", ""),
+            mint_entry("a", "b", "c"),
+            mint_entry("Name #1", "SYM", "https://example.com/1.json"),
+            mint_entry(&"n".repeat(MAX_NAME_LENGTH - 4), &"s".repeat(MAX_SYMBOL_LENGTH - 4), &"u".repeat(MAX_URI_LENGTH - 4)),
+            mint_entry("héllo wörld", "€", "ipfs://😀"),
+        ] {
+            let data = entry.encode().unwrap();
+            assert_eq!(data.len(), MINT_ENTRY_SIZE);
+            let decoded = MintEntry::decode(&data).unwrap();
+            assert_eq!(decoded.name, entry.name);
+            assert_eq!(decoded.symbol, entry.symbol);
+            assert_eq!(decoded.uri, entry.uri);
+            assert_eq!(decoded.weight, entry.weight);
+        }
+    }
+
+    #[test]
+    fn test_mint_entry_field_too_long() {
+        let too_long = u32::from(ErrorCode::MintEntryFieldTooLong);
+        assert_eq!(error_code(mint_entry(&"n".repeat(MAX_NAME_LENGTH - 3), "", "").encode()), too_long);
+        assert_eq!(error_code(mint_entry("", &"s".repeat(MAX_SYMBOL_LENGTH), "").encode()), too_long);
+        assert_eq!(error_code(mint_entry("", "", &"u".repeat(MAX_URI_LENGTH + 1)).encode()), too_long);
+        assert_eq!(error_code(new_mint_entry("n".repeat(MAX_NAME_LENGTH), "".to_string(), "".to_string(), None)), too_long);
+    }
+
+    #[test]
+    fn test_mint_entry_decode_legacy_padding() {
+        // legacy rows have the length prefix covering the NUL padding
+        let mut data = Vec::new();
+        for (value, width) in [("name", MAX_NAME_LENGTH), ("sym", MAX_SYMBOL_LENGTH), ("uri", MAX_URI_LENGTH)] {
+            data.extend_from_slice(&((width - 4) as u32).to_le_bytes());
+            data.extend_from_slice(value.as_bytes());
+            data.resize(data.len() + width - 4 - value.len(), 0);
+        }
+        data.extend_from_slice(&1u16.to_le_bytes());
+        let decoded = MintEntry::decode(&data).unwrap();
+        assert_eq!(decoded.name, "name");
+        assert_eq!(decoded.symbol, "sym");
+        assert_eq!(decoded.uri, "uri");
+        assert_eq!(decoded.weight, 1);
+    }
+
+    #[test]
+    fn test_mint_entry_decode_invalid() {
+        let invalid_data = u32::from(ErrorCode::InvalidMintEntryData);
+        let mut data = mint_entry("name", "sym", "uri").encode().unwrap();
+        assert_eq!(error_code(MintEntry::decode(&data[1..])), invalid_data);
+
+        // invalid utf8
+        data[4] = 0xff;
+        assert_eq!(error_code(MintEntry::decode(&data)), invalid_data);
+
+        // length prefix past the field width
+        let mut data = mint_entry("name", "sym", "uri").encode().unwrap();
+        data[0..4].copy_from_slice(&(MAX_NAME_LENGTH as u32).to_le_bytes());
+        assert_eq!(error_code(MintEntry::decode(&data)), invalid_data);
+    }
+
+    #[test]
+    fn test_weighted_index() {
+        let weights: Vec<u16> = (0..300).map(|i| (i % 5 + 1) as u16).collect();
+        // rows start after a header
+        let mut data = vec![0; 8];
+        for weight in weights.iter() {
+            data.extend_from_slice(
+                &MintEntry {
+                    weight: *weight,
+                    ..mint_entry("name", "sym", "uri")
+                }
+                .encode()
+                .unwrap(),
+            );
+        }
+        let weight_block_sums = weight_block_sums_of(&data, 8, weights.len() as u64);
+        assert_eq!(weight_block_sums.len(), 3);
+        assert_eq!(weight_block_sums.iter().sum::<u64>(), weights.iter().map(|w| *w as u64).sum::<u64>());
+
+        // every target picks the same row as a walk over every row
+        let mut cumulative_weight: u64 = 0;
+        for (index, weight) in weights.iter().enumerate() {
+            for target_weight in cumulative_weight..cumulative_weight + *weight as u64 {
+                assert_eq!(weighted_index(&data, 8, weights.len(), &weight_block_sums, target_weight).unwrap(), index);
+            }
+            cumulative_weight += *weight as u64;
+        }
+        assert_eq!(
+            error_code(weighted_index(&data, 8, weights.len(), &weight_block_sums, cumulative_weight)),
+            u32::from(ErrorCode::InvalidIndex)
+        );
+
+        assert_eq!(unit_weight_block_sums(300), vec![128, 128, 44]);
+        assert_eq!(unit_weight_block_sums(0), Vec::<u64>::new());
+    }
+
+    #[test]
+    fn test_merkle_unconsumed_index() {
+        // indices 0, 1 and 9 consumed out of 10
+        let bitmap = [0b0000_0011, 0b0000_0010];
+        assert_eq!(merkle_unconsumed_index(&bitmap, 10, 0).unwrap(), 2);
+        assert_eq!(merkle_unconsumed_index(&bitmap, 10, 5).unwrap(), 7);
+        assert_eq!(merkle_unconsumed_index(&bitmap, 10, 6).unwrap(), 8);
+        // bits past the supply are never picked
+        assert!(merkle_unconsumed_index(&bitmap, 10, 7).is_err());
+    }
+
+    #[test]
+    fn test_verify_merkle_proof() {
+        let leaves = (0..3u64)
+            .map(|i| merkle_leaf(i, &mint_entry(&format!("Name #{}", i), "SYM", "uri")).unwrap())
+            .collect::<Vec<[u8; 32]>>();
+        let node = |a: [u8; 32], b: [u8; 32]| if a <= b { hashv(&[&[1], &a, &b]).to_bytes() } else { hashv(&[&[1], &b, &a]).to_bytes() };
+        let root = node(node(leaves[0], leaves[1]), leaves[2]);
+
+        assert!(verify_merkle_proof(&[leaves[0], leaves[2]], &root, leaves[1]));
+        assert!(verify_merkle_proof(&[node(leaves[0], leaves[1])], &root, leaves[2]));
+        assert!(!verify_merkle_proof(&[leaves[0], leaves[2]], &root, leaves[2]));
+        assert!(!verify_merkle_proof(&[leaves[1], leaves[2]], &root, merkle_leaf(0, &mint_entry("Other", "SYM", "uri")).unwrap()));
+    }
+
+    #[test]
+    fn test_mint_entries_digest_inline() {
+        let entries_data = [mint_entry("a", "b", "c").encode().unwrap(), mint_entry("d", "e", "f").encode().unwrap()].concat();
+        assert_eq!(mint_entries_digest(&MintEntryStorage::Inline, &[&entries_data]).unwrap(), hash(&entries_data).to_bytes());
+    }
+
+    #[test]
+    fn test_mint_entries_digest_paged() {
+        let first_page = mint_entry("a", "b", "c").encode().unwrap();
+        let second_page = mint_entry("d", "e", "f").encode().unwrap();
+        let entry_storage = MintEntryStorage::Paged { page_remaining: vec![1, 1] };
+        // pages hash the same as the entries stored inline
+        assert_eq!(
+            mint_entries_digest(&entry_storage, &[&first_page, &second_page]).unwrap(),
+            hash(&[first_page.as_slice(), second_page.as_slice()].concat()).to_bytes()
+        );
+    }
+
+    #[test]
+    fn test_mint_entries_digest_template() {
+        let template = |name_prefix: &str| MintEntryStorage::Template {
+            template: MintEntryTemplate {
+                name_prefix: name_prefix.to_string(),
+                symbol: "SYM".to_string(),
+                uri_prefix: "https://host/".to_string(),
+                uri_suffix: ".json".to_string(),
+                index_offset: 1,
+            },
+        };
+        let index_table = [0u8; 2 * MINT_ENTRY_TEMPLATE_INDEX_SIZE];
+        let digest = mint_entries_digest(&template("Foo #"), &[&index_table]).unwrap();
+        assert_ne!(digest, hash(&index_table).to_bytes());
+        // the template is part of the digest
+        assert_ne!(digest, mint_entries_digest(&template("Bar #"), &[&index_table]).unwrap());
+        assert_eq!(digest, mint_entries_digest(&template("Foo #"), &[&index_table]).unwrap());
+    }
+
+    #[test]
+    fn test_mint_entries_digest_merkle() {
+        let bitmap = [0u8; 2];
+        let digest = mint_entries_digest(&MintEntryStorage::Merkle { root: [1; 32] }, &[&bitmap]).unwrap();
+        assert_ne!(digest, hash(&bitmap).to_bytes());
+        // the root is part of the digest
+        assert_ne!(digest, mint_entries_digest(&MintEntryStorage::Merkle { root: [2; 32] }, &[&bitmap]).unwrap());
+        assert_eq!(digest, mint_entries_digest(&MintEntryStorage::Merkle { root: [1; 32] }, &[&bitmap]).unwrap());
+    }
+
+    #[test]
+    fn test_dutch_auction_price() {
+        let price_curve = MintPhasePriceCurve::DutchAuction {
+            start_seconds: 1_000,
+            start_price: 10_000,
+            floor_price: 2_500,
+            decay_per_interval: 1_000,
+            interval_seconds: 60,
+        };
+        assert!(price_curve.check_valid().is_ok());
+        assert_eq!(price_curve.price(0, 0), 10_000);
+        assert_eq!(price_curve.price(1_059, 0), 10_000);
+        assert_eq!(price_curve.price(1_060, 0), 9_000);
+        assert_eq!(price_curve.price(1_000 + 60 * 7, 0), 3_000);
+        assert_eq!(price_curve.price(1_000 + 60 * 8, 0), 2_500);
+        assert_eq!(price_curve.price(i64::MAX, 0), 2_500);
+    }
+
+    #[test]
+    fn test_count_price_curves() {
+        let linear = MintPhasePriceCurve::Linear {
+            start_price: 1_000,
+            increment_per_mint: 10,
+        };
+        assert_eq!(linear.price(0, 0), 1_000);
+        assert_eq!(linear.price(0, 250), 3_500);
+
+        let tiers = MintPhasePriceCurve::Tiers {
+            tiers: vec![MintPhasePriceTier { up_to_count: 1_000, price: 100 }, MintPhasePriceTier { up_to_count: 2_000, price: 200 }],
+        };
+        assert!(tiers.check_valid().is_ok());
+        assert_eq!(tiers.price(0, 0), 100);
+        assert_eq!(tiers.price(0, 999), 100);
+        assert_eq!(tiers.price(0, 1_000), 200);
+        assert_eq!(tiers.price(0, 5_000), 200);
+
+        assert!(MintPhasePriceCurve::Tiers { tiers: vec![] }.check_valid().is_err());
+        assert!(MintPhasePriceCurve::Tiers {
+            tiers: vec![MintPhasePriceTier { up_to_count: 1_000, price: 100 }, MintPhasePriceTier { up_to_count: 1_000, price: 200 }],
+        }
+        .check_valid()
+        .is_err());
+    }
+
+    fn mint_config(mint_phases: Vec<MintPhase>) -> MintConfig {
+        MintConfig {
+            bump: 0,
+            authority: Pubkey::default(),
+            name: "test".to_string(),
+            supply: 0,
+            count: 0,
+            output_mint_config: OutputMintConfig {
+                seller_fee_basis_points: 500,
+                token_standard: TokenStandard::NonFungible,
+                collection: None,
+                ruleset: None,
+                creators: vec![],
+                merkle_tree: None,
+                release_authority: None,
+                randomness_source: RandomnessSource::SlotHashes,
+                selection_mode: MintSelectionMode::Random,
+                hidden_settings: None,
+            },
+            mint_phases,
+            metadata: "".to_string(),
+            pending_reveals: 0,
+            total_weight: 0,
+            provenance_hash: None,
+            entry_storage: MintEntryStorage::Inline,
+            max_per_wallet_total: None,
+            paused: false,
+            pause_authority: None,
+            phase_selection: MintPhaseSelection::First,
+            bot_tax_lamports: None,
+            weight_block_sums: vec![],
+        }
+    }
+
+    #[test]
+    fn test_phase_output_overrides() {
+        let release_authority = Pubkey::new_unique();
+        let mut mint_config = mint_config(vec![
+            MintPhase {
+                output_overrides: Some(MintPhaseOutputOverrides {
+                    token_standard: Some(TokenStandard::ProgrammableNonFungible),
+                    release_authority: Some(release_authority),
+                    ..MintPhaseOutputOverrides::default()
+                }),
+                ..MintPhase::default()
+            },
+            MintPhase::default(),
+        ]);
+        assert!(mint_config.check_valid().is_ok());
+
+        let team = mint_config.phase_output_mint_config(0);
+        assert_eq!(team.token_standard, TokenStandard::ProgrammableNonFungible);
+        assert_eq!(team.release_authority, Some(release_authority));
+        assert_eq!(team.seller_fee_basis_points, 500);
+
+        let public = mint_config.phase_output_mint_config(1);
+        assert_eq!(public.token_standard, TokenStandard::NonFungible);
+        assert_eq!(public.release_authority, None);
+
+        // overrides are validated against the resolved config
+        mint_config.output_mint_config.merkle_tree = Some(Pubkey::new_unique());
+        assert_eq!(error_code(mint_config.check_valid()), u32::from(ErrorCode::ProgrammableAndMerkleTree));
+        mint_config.output_mint_config.merkle_tree = None;
+        mint_config.mint_phases[1].output_overrides = Some(MintPhaseOutputOverrides {
+            creators: Some(vec![
+                Creator {
+                    address: Pubkey::new_unique(),
+                    share: 50,
+                };
+                MAX_CREATOR_LIMIT
+            ]),
+            ..MintPhaseOutputOverrides::default()
+        });
+        assert_eq!(error_code(mint_config.check_valid()), u32::from(ErrorCode::TooManyCreators));
+    }
+
+    #[test]
+    fn test_check_update() {
+        let mut mint_config = mint_config(vec![]);
+        let mut new_mint_config = mint_config.clone();
+        new_mint_config.output_mint_config.selection_mode = MintSelectionMode::Sequential;
+        new_mint_config.output_mint_config.hidden_settings = Some(HiddenSettings {
+            name: "hidden".to_string(),
+            uri: "https://hidden".to_string(),
+            reveal_authority: None,
+        });
+        assert!(mint_config.check_update(&new_mint_config).is_ok());
+
+        mint_config.pending_reveals = 1;
+        assert_eq!(error_code(mint_config.check_update(&new_mint_config)), u32::from(ErrorCode::MintingAlreadyStarted));
+        mint_config.pending_reveals = 0;
+        mint_config.count = 1;
+        assert_eq!(error_code(mint_config.check_update(&new_mint_config)), u32::from(ErrorCode::MintingAlreadyStarted));
+
+        // other settings can still change
+        let mut new_mint_config = mint_config.clone();
+        new_mint_config.output_mint_config.seller_fee_basis_points = 1_000;
+        assert!(mint_config.check_update(&new_mint_config).is_ok());
+    }
+
+    #[test]
+    fn test_mint_entry_page_position() {
+        let mut mint_config = mint_config(vec![]);
+        assert_eq!(error_code(mint_entry_page_position(&mint_config, 0)), u32::from(ErrorCode::InvalidMintEntryStorage));
+
+        mint_config.entry_storage = MintEntryStorage::Paged { page_remaining: vec![2, 0, 3] };
+        assert_eq!(mint_entry_page_position(&mint_config, 0).unwrap(), (0, 0));
+        assert_eq!(mint_entry_page_position(&mint_config, 1).unwrap(), (0, 1));
+        // empty pages are skipped
+        assert_eq!(mint_entry_page_position(&mint_config, 2).unwrap(), (2, 0));
+        assert_eq!(mint_entry_page_position(&mint_config, 4).unwrap(), (2, 2));
+        assert_eq!(error_code(mint_entry_page_position(&mint_config, 5)), u32::from(ErrorCode::InvalidIndex));
+
+        // the picked page must be known before the mint
+        mint_config.output_mint_config.selection_mode = MintSelectionMode::Random;
+        mint_config.output_mint_config.randomness_source = RandomnessSource::SlotHashes;
+        assert_eq!(error_code(mint_config.check_valid()), u32::from(ErrorCode::PagedAndRandomnessSource));
+    }
+
+    #[test]
+    fn test_sequential_mint_entries_length() {
+        let mut mint_config = mint_config(vec![]);
+        mint_config.supply = 10;
+        mint_config.count = 4;
+        assert_eq!(mint_config.mint_entries_length(), 6 * MINT_ENTRY_SIZE);
+
+        // consumed entries stay ahead of the remaining ones
+        mint_config.output_mint_config.selection_mode = MintSelectionMode::Sequential;
+        assert_eq!(mint_config.mint_entries_length(), 10 * MINT_ENTRY_SIZE);
+        mint_config.entry_storage = MintEntryStorage::Paged { page_remaining: vec![6] };
+        assert_eq!(mint_config.mint_entries_length(), 0);
+
+        let mint_entry_page = MintEntryPage {
+            supply: 10,
+            count: 4,
+            ..MintEntryPage::default()
+        };
+        assert_eq!(mint_entry_page.remaining_entries_start(&MintSelectionMode::Random), MINT_ENTRY_PAGE_HEADER_SIZE);
+        assert_eq!(
+            mint_entry_page.remaining_entries_start(&MintSelectionMode::Sequential),
+            MINT_ENTRY_PAGE_HEADER_SIZE + 4 * MINT_ENTRY_SIZE
+        );
+    }
+
+    #[test]
+    fn test_phase_check_active() {
+        let context = ConditionContext {
+            timestamp: 1_000,
+            slot: 500,
+            count: 10,
+            mint_phases: &[],
+        };
+        let mint_phase = MintPhase {
+            start_condition: Some(MintPhaseCondition::Slot { slot: 600 }),
+            end_condition: Some(MintPhaseCondition::Slot { slot: 400 }),
+            max_supply: Some(0),
+            ..MintPhase::default()
+        };
+        // start is checked first and only the authority skips it
+        assert_eq!(error_code(mint_phase.check_active(&context, false).into_result()), u32::from(ErrorCode::PhaseNotStarted));
+        assert_eq!(error_code(mint_phase.check_active(&context, true).into_result()), u32::from(ErrorCode::PhaseEnded));
+
+        let mint_phase = MintPhase {
+            end_condition: Some(MintPhaseCondition::Slot { slot: 501 }),
+            ..mint_phase
+        };
+        assert_eq!(error_code(mint_phase.check_active(&context, true).into_result()), u32::from(ErrorCode::PhaseSoldOut));
+
+        let mint_phase = MintPhase { max_supply: None, ..mint_phase };
+        assert!(mint_phase.check_active(&context, true).into_result().is_ok());
+    }
+
+    #[test]
+    fn test_output_mint_pending_release_load() {
+        let key = Pubkey::new_unique();
+        let owner = crate::id();
+        let release_authority = Pubkey::new_unique();
+        let mut mint_config = mint_config(vec![]);
+        let output_mint_pending_release = OutputMintPendingRelease {
+            bump: 254,
+            mint_config: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            holder: Pubkey::new_unique(),
+            release_authority,
+        };
+        let mut data = Vec::new();
+        output_mint_pending_release.try_serialize(&mut data).unwrap();
+
+        let mut lamports = 0;
+        let account_info = AccountInfo::new(&key, false, true, &mut lamports, &mut data, &owner, false, 0);
+        let (loaded, loaded_release_authority) = OutputMintPendingRelease::load(&account_info, &mint_config).unwrap();
+        assert_eq!(loaded.holder, output_mint_pending_release.holder);
+        assert_eq!(loaded_release_authority, Some(release_authority));
+
+        // legacy layout uses the mint config release authority
+        let mut legacy_data = data[..OUTPUT_MINT_PENDING_RELEASE_LEGACY_SIZE].to_vec();
+        let mut lamports = 0;
+        let account_info = AccountInfo::new(&key, false, true, &mut lamports, &mut legacy_data, &owner, false, 0);
+        let (loaded, loaded_release_authority) = OutputMintPendingRelease::load(&account_info, &mint_config).unwrap();
+        assert_eq!(loaded.mint, output_mint_pending_release.mint);
+        assert_eq!(loaded_release_authority, None);
+        let config_release_authority = Pubkey::new_unique();
+        mint_config.output_mint_config.release_authority = Some(config_release_authority);
+        let (_, loaded_release_authority) = OutputMintPendingRelease::load(&account_info, &mint_config).unwrap();
+        assert_eq!(loaded_release_authority, Some(config_release_authority));
+
+        let mut short_data = data[..OUTPUT_MINT_PENDING_RELEASE_LEGACY_SIZE - 1].to_vec();
+        let mut lamports = 0;
+        let account_info = AccountInfo::new(&key, false, true, &mut lamports, &mut short_data, &owner, false, 0);
+        assert_eq!(
+            error_code(OutputMintPendingRelease::load(&account_info, &mint_config)),
+            u32::from(ErrorCode::InvalidOutputMintsPendingRelease)
+        );
+    }
+
+    #[test]
+    fn test_pending_mint_is_expired() {
+        let mut pending_mint = PendingMint {
+            commit_slot: 100,
+            reveal_slot: 101,
+            ..PendingMint::default()
+        };
+        assert!(!pending_mint.is_expired(100));
+        assert!(!pending_mint.is_expired(101 + PENDING_MINT_REVEAL_SLOTS));
+        assert!(pending_mint.is_expired(102 + PENDING_MINT_REVEAL_SLOTS));
+
+        // the window starts once the oracle fulfills the request
+        pending_mint.vrf_randomness = Some(Pubkey::new_unique());
+        assert!(!pending_mint.is_expired(100 + PENDING_MINT_EXPIRY_SLOTS));
+        assert!(pending_mint.is_expired(101 + PENDING_MINT_EXPIRY_SLOTS));
+        pending_mint.randomness = Some([7; 32]);
+        pending_mint.reveal_slot = 100 + PENDING_MINT_EXPIRY_SLOTS;
+        assert!(!pending_mint.is_expired(100 + PENDING_MINT_EXPIRY_SLOTS + PENDING_MINT_REVEAL_SLOTS));
+        assert!(pending_mint.is_expired(101 + PENDING_MINT_EXPIRY_SLOTS + PENDING_MINT_REVEAL_SLOTS));
+    }
+
+    #[test]
+    fn test_vrf_randomness_load() {
+        let key = Pubkey::new_unique();
+        let oracle_program = Pubkey::new_unique();
+        let vrf_randomness = VrfRandomness {
+            discriminator: VRF_RANDOMNESS_DISCRIMINATOR,
+            requester: Pubkey::new_unique(),
+            seed: [1; 32],
+            fulfilled: true,
+            randomness: [2; 32],
+        };
+        let mut data = vrf_randomness.try_to_vec().unwrap();
+        assert_eq!(data.len(), VRF_RANDOMNESS_SIZE);
+        let mut lamports = 0;
+
+        {
+            let account_info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &oracle_program, false, 0);
+            let loaded = VrfRandomness::load(&account_info, &oracle_program).unwrap();
+            assert_eq!(loaded.requester, vrf_randomness.requester);
+            assert_eq!(loaded.seed, [1; 32]);
+            assert!(loaded.fulfilled);
+            assert_eq!(loaded.randomness, [2; 32]);
+
+            // written by another program
+            assert_eq!(error_code(VrfRandomness::load(&account_info, &Pubkey::new_unique())), u32::from(ErrorCode::InvalidVrfRandomness));
+        }
+
+        // trailing oracle data is ignored
+        let mut long_data = data.clone();
+        long_data.extend_from_slice(&[0; 16]);
+        {
+            let account_info = AccountInfo::new(&key, false, false, &mut lamports, &mut long_data, &oracle_program, false, 0);
+            assert!(VrfRandomness::load(&account_info, &oracle_program).is_ok());
+        }
+
+        // too short
+        let mut short_data = data[..VRF_RANDOMNESS_SIZE - 1].to_vec();
+        {
+            let account_info = AccountInfo::new(&key, false, false, &mut lamports, &mut short_data, &oracle_program, false, 0);
+            assert_eq!(error_code(VrfRandomness::load(&account_info, &oracle_program)), u32::from(ErrorCode::InvalidVrfRandomness));
+        }
+
+        // another account type of the oracle
+        data[0] ^= 1;
+        let account_info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &oracle_program, false, 0);
+        assert_eq!(error_code(VrfRandomness::load(&account_info, &oracle_program)), u32::from(ErrorCode::InvalidVrfRandomness));
+    }
+
+    #[test]
+    fn test_migrate_mint_config() {
+        let mint_config_v0 = MintConfigV0 {
+            bump: 255,
+            authority: Pubkey::new_unique(),
+            name: "legacy".to_string(),
+            supply: 3,
+            count: 1,
+            output_mint_config: OutputMintConfigV0 {
+                seller_fee_basis_points: 500,
+                token_standard: TokenStandard::NonFungible,
+                collection: None,
+                ruleset: None,
+                creators: vec![],
+                merkle_tree: None,
+                release_authority: None,
+            },
+            mint_phases: vec![MintPhaseV0 {
+                start_condition: Some(MintPhaseStartEndConditionV0 {
+                    time_seconds: Some(1_000),
+                    count: None,
+                }),
+                end_condition: Some(MintPhaseStartEndConditionV0 { time_seconds: None, count: None }),
+                token_checks: vec![],
+                authorization: None,
+                metadata: "".to_string(),
+            }],
+            metadata: "{}".to_string(),
+        };
+        let mint_entries = vec![mint_entry("a", "A", "https://a"), mint_entry("b", "B", "https://b")];
+        let mut data = MintConfig::discriminator().to_vec();
+        mint_config_v0.serialize(&mut data).unwrap();
+        let legacy_start = data.len();
+        for mint_entry in &mint_entries {
+            data.extend_from_slice(&mint_entry.encode().unwrap()[..MINT_ENTRY_V0_SIZE]);
+        }
+
+        let new_mint_config = mint_config_v0.migrate();
+        assert_eq!(new_mint_config.total_weight, 2);
+        data.resize(new_mint_config.account_size(), 0);
+        let new_start = data.len() - new_mint_config.mint_entries_length();
+        migrate_mint_entries(&mut data, legacy_start, new_start, 2);
+        new_mint_config.try_serialize(&mut &mut data[..new_start]).unwrap();
+
+        let migrated = MintConfig::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(migrated.name, "legacy");
+        assert_eq!(migrated.remaining_tokens(), 2);
+        for (i, mint_entry) in mint_entries.iter().enumerate() {
+            let position = new_start + i * MINT_ENTRY_SIZE;
+            let migrated_entry = MintEntry::decode(&data[position..position + MINT_ENTRY_SIZE]).unwrap();
+            assert_eq!(migrated_entry.name, mint_entry.name);
+            assert_eq!(migrated_entry.uri, mint_entry.uri);
+            assert_eq!(migrated_entry.weight, 1);
+        }
+
+        // legacy conditions are met once any set field is reached
+        let context = ConditionContext {
+            timestamp: 1_000,
+            slot: 0,
+            count: 0,
+            mint_phases: &[],
+        };
+        let mint_phase = &migrated.mint_phases[0];
+        assert!(mint_phase.start_condition.as_ref().unwrap().evaluate(&context));
+        assert!(!mint_phase.end_condition.as_ref().unwrap().evaluate(&context));
+    }
+
+    #[test]
+    fn test_mint_cooldown() {
+        let mut mint_cooldown = MintCooldown {
+            last_mint_seconds: i64::MIN,
+            ..MintCooldown::default()
+        };
+        assert!(!mint_cooldown.is_active(60, 1_000));
+
+        mint_cooldown.last_mint_seconds = 1_000;
+        assert!(mint_cooldown.is_active(60, 1_000));
+        assert!(mint_cooldown.is_active(60, 1_059));
+        assert!(!mint_cooldown.is_active(60, 1_060));
+
+        for cooldown_seconds in [0, -1].iter() {
+            let mint_phase = MintPhase {
+                cooldown_seconds: Some(*cooldown_seconds),
+                ..MintPhase::default()
+            };
+            assert_eq!(error_code(mint_phase.check_valid()), u32::from(ErrorCode::InvalidMintCooldown));
+        }
+    }
+}
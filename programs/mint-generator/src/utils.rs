@@ -1,9 +1,15 @@
 use anchor_lang::prelude::*;
 use anchor_lang::Result;
 use arrayref::array_ref;
+use solana_program::hash::hashv;
 use solana_program::program::invoke;
+use solana_program::program::invoke_signed;
+use solana_program::system_instruction::allocate;
+use solana_program::system_instruction::assign;
+use solana_program::system_instruction::create_account;
 use solana_program::system_instruction::transfer;
 use std::cmp::Ordering;
+use std::convert::TryFrom;
 use std::convert::TryInto;
 
 pub fn resize_account<'info>(account_info: &AccountInfo<'info>, new_space: usize, payer: &AccountInfo<'info>, system_program: &AccountInfo<'info>) -> Result<()> {
@@ -31,9 +37,70 @@ pub fn resize_account<'info>(account_info: &AccountInfo<'info>, new_space: usize
     Ok(())
 }
 
+// creates the PDA owned by `owner` - an address that was already sent lamports is topped up to rent and allocated instead so it cannot be blocked
+pub fn create_pda_account<'info>(account_info: &AccountInfo<'info>, space: usize, owner: &Pubkey, seeds: &[Vec<u8>], payer: &AccountInfo<'info>, system_program: &AccountInfo<'info>) -> Result<()> {
+    let signer_seeds = seeds.iter().map(|s| s.as_slice()).collect::<Vec<&[u8]>>();
+    let minimum_balance = Rent::get()?.minimum_balance(space);
+    let current_balance = account_info.lamports();
+    if current_balance == 0 {
+        invoke_signed(
+            &create_account(&payer.key(), &account_info.key(), minimum_balance, space as u64, owner),
+            &[payer.clone(), account_info.clone(), system_program.clone()],
+            &[&signer_seeds],
+        )?;
+        return Ok(());
+    }
+
+    if minimum_balance > current_balance {
+        invoke(
+            &transfer(&payer.key(), &account_info.key(), minimum_balance.saturating_sub(current_balance)),
+            &[payer.clone(), account_info.clone(), system_program.clone()],
+        )?;
+    }
+    invoke_signed(&allocate(&account_info.key(), space as u64), &[account_info.clone(), system_program.clone()], &[&signer_seeds])?;
+    invoke_signed(&assign(&account_info.key(), owner), &[account_info.clone(), system_program.clone()], &[&signer_seeds])?;
+    Ok(())
+}
+
+// moves all lamports to the collector and returns the account to the system program
+pub fn close_account<'info>(account_info: &AccountInfo<'info>, collector: &AccountInfo<'info>) -> Result<()> {
+    **collector.try_borrow_mut_lamports()? = collector.lamports().checked_add(account_info.lamports()).expect("Add error");
+    **account_info.try_borrow_mut_lamports()? = 0;
+    account_info.assign(&solana_program::system_program::ID);
+    account_info.realloc(0, false)?;
+    Ok(())
+}
+
 pub fn pseudo_random_number<'info>(recent_slothashes: &AccountInfo<'info>) -> Result<u64> {
     let recent_slothashes_data = recent_slothashes.data.borrow();
     let recent_slothash = array_ref![recent_slothashes_data, 12, 8];
     let timestamp = Clock::get()?.unix_timestamp;
     Ok(u64::from_le_bytes(*recent_slothash).saturating_sub(timestamp.try_into().expect("Conversion error")))
 }
+
+pub const SLOT_HASH_ENTRY_SIZE: usize = 8 + 32;
+
+pub fn recent_slothash<'info>(recent_slothashes: &AccountInfo<'info>, slot: u64) -> Result<Option<[u8; 32]>> {
+    let recent_slothashes_data = recent_slothashes.data.borrow();
+    let entries = u64::from_le_bytes(*array_ref![recent_slothashes_data, 0, 8]);
+    for i in 0..usize::try_from(entries).expect("Conversion error") {
+        let entry_start = i.checked_mul(SLOT_HASH_ENTRY_SIZE).expect("Mul error").checked_add(8).expect("Add error");
+        if entry_start.checked_add(SLOT_HASH_ENTRY_SIZE).expect("Add error") > recent_slothashes_data.len() {
+            break;
+        }
+        let entry_slot = u64::from_le_bytes(*array_ref![recent_slothashes_data, entry_start, 8]);
+        if entry_slot == slot {
+            return Ok(Some(*array_ref![recent_slothashes_data, entry_start + 8, 32]));
+        }
+        // entries are ordered from most recent to oldest
+        if entry_slot < slot {
+            break;
+        }
+    }
+    Ok(None)
+}
+
+pub fn commit_reveal_random_number(slothash: &[u8; 32], commit_hash: &[u8; 32]) -> u64 {
+    let random_hash = hashv(&[slothash.as_ref(), commit_hash.as_ref()]).to_bytes();
+    u64::from_le_bytes(*array_ref![random_hash, 0, 8])
+}
@@ -0,0 +1,114 @@
+use crate::errors::ErrorCode;
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_program::program::set_return_data;
+use std::cmp;
+use std::convert::TryFrom;
+use std::slice::Iter;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GetActivePhasesIx {
+    user: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ActivePhase {
+    pub mint_phase_index: u8,
+    // mints left for the user from their authorization record and per wallet limit (NOTE: None when not limited)
+    pub remaining: Option<u64>,
+}
+
+#[derive(Accounts)]
+pub struct GetActivePhasesCtx<'info> {
+    mint_config: Box<Account<'info, MintConfig>>,
+}
+
+// remaining accounts are passed for each phase in phase order the same way `mint` reads them after its token check accounts
+// 1. user, holder and mint phase authorization when the phase has an authorization check (NOTE: the holder does not need to sign)
+// 2. mint cooldown when the phase has a cooldown
+// 3. mint counter when the phase has a per wallet limit
+// 4. mint wallet counter when the mint config has a total per wallet limit
+// NOTE: token check accounts are not passed as holdings are not checked
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, GetActivePhasesCtx<'info>>, ix: GetActivePhasesIx) -> Result<()> {
+    // no phase is active while minting is paused or every token is minted or committed
+    let mint_config = &ctx.accounts.mint_config;
+    let active_phases = if mint_config.paused || mint_config.available_tokens() == 0 {
+        Vec::new()
+    } else {
+        active_phases(mint_config, &ix.user, &mut ctx.remaining_accounts.iter())?
+    };
+    set_return_data(&active_phases.try_to_vec()?);
+    Ok(())
+}
+
+// phases the user can currently mint from in phase order
+pub fn active_phases<'info>(mint_config: &Account<'info, MintConfig>, user: &Pubkey, remaining_accounts: &mut Iter<AccountInfo<'info>>) -> Result<Vec<ActivePhase>> {
+    let is_authority = *user == mint_config.authority;
+    let mut active_phases = Vec::new();
+    for (i, mint_phase) in mint_config.mint_phases.iter().enumerate() {
+        let mint_phase_ix = u8::try_from(i).expect("Conversion error");
+        let mut remaining = None;
+
+        // NOTE: accounts are always read so later phases stay aligned
+        if let Some(authorization) = &mint_phase.authorization {
+            next_account_info(remaining_accounts)?;
+            next_account_info(remaining_accounts)?;
+            let mint_phase_authorization_account_info = next_account_info(remaining_accounts)?;
+            MintPhaseAuthorization::seeds(&mint_config.key(), mint_phase_ix, user, &mint_phase_authorization_account_info.key())?;
+            if mint_phase_authorization_account_info.data_is_empty() {
+                if authorization.mode == MintPhaseAuthorizationMode::DefaultDisallowed {
+                    remaining = Some(0);
+                }
+            } else {
+                let mint_phase_authorization = Account::<MintPhaseAuthorization>::try_from(mint_phase_authorization_account_info)?;
+                if mint_phase_authorization.mint_config != mint_config.key() || mint_phase_authorization.mint_phase_index != mint_phase_ix || mint_phase_authorization.user != *user {
+                    return Err(error!(ErrorCode::InvalidMintPhaseAuthorization));
+                }
+                remaining = mint_phase_authorization.remaining;
+            }
+        }
+
+        if let Some(cooldown_seconds) = mint_phase.cooldown_seconds {
+            let mint_cooldown_info = next_account_info(remaining_accounts)?;
+            MintCooldown::seeds(&mint_config.key(), mint_phase_ix, user, &mint_cooldown_info.key())?;
+            if !mint_cooldown_info.data_is_empty() && Account::<MintCooldown>::try_from(mint_cooldown_info)?.is_active(cooldown_seconds, Clock::get()?.unix_timestamp) {
+                remaining = Some(0);
+            }
+        }
+
+        if let Some(max_per_wallet) = mint_phase.max_per_wallet {
+            let mint_counter_info = next_account_info(remaining_accounts)?;
+            MintCounter::seeds(&mint_config.key(), mint_phase_ix, user, &mint_counter_info.key())?;
+            let count = if mint_counter_info.data_is_empty() {
+                0
+            } else {
+                Account::<MintCounter>::try_from(mint_counter_info)?.count
+            };
+            remaining = Some(min_remaining(remaining, max_per_wallet.saturating_sub(count)));
+        }
+
+        if let Some(max_per_wallet_total) = mint_config.max_per_wallet_total {
+            let mint_wallet_counter_info = next_account_info(remaining_accounts)?;
+            MintWalletCounter::seeds(&mint_config.key(), user, &mint_wallet_counter_info.key())?;
+            let count = if mint_wallet_counter_info.data_is_empty() {
+                0
+            } else {
+                Account::<MintWalletCounter>::try_from(mint_wallet_counter_info)?.count
+            };
+            remaining = Some(min_remaining(remaining, max_per_wallet_total.saturating_sub(count)));
+        }
+
+        // same phase checks as `mint` - start and end conditions and the phase max supply
+        if remaining != Some(0) && matches!(validate_phase(mint_config, mint_phase_ix, is_authority)?, MintValidation::Valid(_)) {
+            active_phases.push(ActivePhase {
+                mint_phase_index: mint_phase_ix,
+                remaining,
+            });
+        }
+    }
+    Ok(active_phases)
+}
+
+fn min_remaining(remaining: Option<u64>, limit_remaining: u64) -> u64 {
+    remaining.map_or(limit_remaining, |r| cmp::min(r, limit_remaining))
+}
@@ -0,0 +1,21 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_program::program::set_return_data;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GetMintEntryIx {
+    // index of the entry among the remaining entries
+    index: u64,
+}
+
+#[derive(Accounts)]
+pub struct GetMintEntryCtx<'info> {
+    mint_config: Box<Account<'info, MintConfig>>,
+}
+
+pub fn handler<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, GetMintEntryCtx<'info>>, ix: GetMintEntryIx) -> Result<()> {
+    // mint entry pages are passed when using paged storage
+    let mint_entry = read_mint_entry(&ctx.accounts.mint_config, ix.index, &mut ctx.remaining_accounts.iter())?;
+    set_return_data(&mint_entry.try_to_vec()?);
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use crate::state::*;
+use anchor_lang::prelude::*;
+use solana_program::program::set_return_data;
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RemainingSupply {
+    pub supply: u64,
+    pub count: u64,
+    // committed mints waiting to be revealed
+    pub pending_reveals: u64,
+    // tokens that can still be minted
+    pub available: u64,
+}
+
+#[derive(Accounts)]
+pub struct GetRemainingSupplyCtx<'info> {
+    mint_config: Box<Account<'info, MintConfig>>,
+}
+
+pub fn handler(ctx: Context<GetRemainingSupplyCtx>) -> Result<()> {
+    let mint_config = &ctx.accounts.mint_config;
+    set_return_data(
+        &RemainingSupply {
+            supply: mint_config.supply,
+            count: mint_config.count,
+            pending_reveals: mint_config.pending_reveals,
+            available: mint_config.available_tokens(),
+        }
+        .try_to_vec()?,
+    );
+    Ok(())
+}
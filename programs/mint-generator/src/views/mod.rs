@@ -0,0 +1,8 @@
+pub mod get_mint_entry;
+pub use get_mint_entry::*;
+
+pub mod get_active_phases;
+pub use get_active_phases::*;
+
+pub mod get_remaining_supply;
+pub use get_remaining_supply::*;
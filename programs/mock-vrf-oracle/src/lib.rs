@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use solana_program::instruction::Instruction;
+use solana_program::program::invoke_signed;
+
+declare_id!("VrfMnhARw9B4TC7Zr2y7TMwAHoY2uhou6x3odkdJA8P");
+
+pub const VRF_RANDOMNESS_PREFIX: &str = "vrf-randomness";
+pub const VRF_RANDOMNESS_SIZE: usize = 8 + std::mem::size_of::<VrfRandomness>();
+// anchor sighash of `global:vrf_callback`
+pub const VRF_CALLBACK_DISCRIMINATOR: [u8; 8] = [248, 224, 55, 227, 56, 10, 108, 36];
+
+// Implements the oracle interface documented with `VrfRandomness` in mint-generator `state.rs`
+// NOTE: anyone can fulfill a request with any randomness so this must only be used in tests
+#[program]
+pub mod mock_vrf_oracle {
+    use super::*;
+
+    pub fn request_randomness(ctx: Context<RequestRandomnessCtx>, seed: [u8; 32]) -> Result<()> {
+        let vrf_randomness = &mut ctx.accounts.vrf_randomness;
+        vrf_randomness.requester = ctx.accounts.requester.key();
+        vrf_randomness.seed = seed;
+        vrf_randomness.fulfilled = false;
+        vrf_randomness.randomness = [0; 32];
+        vrf_randomness.bump = *ctx.bumps.get("vrf_randomness").unwrap();
+        vrf_randomness.payer = ctx.accounts.payer.key();
+        Ok(())
+    }
+
+    pub fn fulfill_randomness<'key, 'accounts, 'remaining, 'info>(ctx: Context<'key, 'accounts, 'remaining, 'info, FulfillRandomnessCtx<'info>>, randomness: [u8; 32]) -> Result<()> {
+        let vrf_randomness = &mut ctx.accounts.vrf_randomness;
+        vrf_randomness.fulfilled = true;
+        vrf_randomness.randomness = randomness;
+        // written before the callback reads it
+        vrf_randomness.exit(ctx.program_id)?;
+
+        // the callback accounts are passed as remaining accounts
+        let mut accounts = vec![AccountMeta::new_readonly(vrf_randomness.key(), true), AccountMeta::new(ctx.accounts.requester.key(), false)];
+        let mut account_infos = vec![vrf_randomness.to_account_info(), ctx.accounts.requester.to_account_info()];
+        for remaining_account in ctx.remaining_accounts {
+            accounts.push(if remaining_account.is_writable {
+                AccountMeta::new(remaining_account.key(), false)
+            } else {
+                AccountMeta::new_readonly(remaining_account.key(), false)
+            });
+            account_infos.push(remaining_account.to_account_info());
+        }
+        account_infos.push(ctx.accounts.callback_program.to_account_info());
+
+        let requester = vrf_randomness.requester;
+        let bump = vrf_randomness.bump;
+        invoke_signed(
+            &Instruction {
+                program_id: ctx.accounts.callback_program.key(),
+                accounts,
+                data: VRF_CALLBACK_DISCRIMINATOR.to_vec(),
+            },
+            &account_infos,
+            &[&[VRF_RANDOMNESS_PREFIX.as_bytes(), requester.as_ref(), &[bump]]],
+        )?;
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct RequestRandomnessCtx<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = VRF_RANDOMNESS_SIZE,
+        seeds = [VRF_RANDOMNESS_PREFIX.as_bytes(), requester.key().as_ref()],
+        bump
+    )]
+    vrf_randomness: Account<'info, VrfRandomness>,
+    requester: Signer<'info>,
+    #[account(mut)]
+    payer: Signer<'info>,
+    system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FulfillRandomnessCtx<'info> {
+    // closed once the callback has stored the randomness so the requester can request again
+    #[account(mut, close = payer, constraint = !vrf_randomness.fulfilled @ ErrorCode::AlreadyFulfilled)]
+    vrf_randomness: Account<'info, VrfRandomness>,
+    /// CHECK: Address is checked
+    #[account(mut, address = vrf_randomness.requester)]
+    requester: UncheckedAccount<'info>,
+    /// CHECK: Address is checked
+    #[account(address = *requester.owner)]
+    callback_program: UncheckedAccount<'info>,
+    authority: Signer<'info>,
+    /// CHECK: Address is checked
+    #[account(mut, address = vrf_randomness.payer)]
+    payer: UncheckedAccount<'info>,
+}
+
+// layout up to `randomness` is the layout mint-generator reads
+#[account]
+#[derive(Default, Debug)]
+pub struct VrfRandomness {
+    pub requester: Pubkey,
+    pub seed: [u8; 32],
+    pub fulfilled: bool,
+    pub randomness: [u8; 32],
+    pub bump: u8,
+    // refunded when the randomness account is closed after the callback
+    pub payer: Pubkey,
+}
+
+#[error_code]
+pub enum ErrorCode {
+    #[msg("Randomness already fulfilled")]
+    AlreadyFulfilled = 0,
+}
@@ -10,7 +10,7 @@ import {
   getAssociatedTokenAddressSync,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
-import type { Connection, PublicKey } from "@solana/web3.js";
+import type { AccountMeta, Connection, PublicKey } from "@solana/web3.js";
 import {
   ComputeBudgetProgram,
   Keypair,
@@ -20,18 +20,24 @@ import {
   Transaction,
 } from "@solana/web3.js";
 
-import type { IdlAccountData } from "./";
+import type { IdlAccountData, IdlTypes } from "./";
 import {
   fetchIdlAccount,
   findOutputMintPendingReleaseId,
+  findPendingMintId,
   mintGeneratorProgram,
 } from "./";
 import {
   getRemainingAccountsForTokenChecks,
+  phaseOutputMintConfig,
   remainingAccountsForAuthorization,
   remainingAccountsForCollection,
+  remainingAccountsForHiddenMint,
   remainingAccountsForMintCnft,
+  remainingAccountsForMintEntryPage,
+  remainingAccountsForMintGuards,
   remainingAccountsForMintNft,
+  remainingAccountsForPendingMint,
   remainingAccountsForRelease,
 } from "./utils";
 
@@ -45,6 +51,9 @@ export const mint = async (
     outputMintKeypair?: Keypair;
     programId?: PublicKey;
     computeLimit?: number;
+    merkleMintEntry?: IdlTypes["MerkleMintEntry"];
+    mintEntryPageIndex?: number;
+    vrfRandomnessId?: PublicKey;
   }
 ): Promise<[Transaction, Keypair | null]> => {
   const mintConfig = await fetchIdlAccount(
@@ -65,6 +74,9 @@ export const mintSync = async (
     outputMintKeypair?: Keypair;
     programId?: PublicKey;
     computeLimit?: number;
+    merkleMintEntry?: IdlTypes["MerkleMintEntry"];
+    mintEntryPageIndex?: number;
+    vrfRandomnessId?: PublicKey;
   }
 ): Promise<[Transaction, Keypair | null]> => {
   const mintPhase = mintConfig.parsed.mintPhases[mintPhaseIx];
@@ -89,38 +101,40 @@ export const mintSync = async (
       options?.payer ?? wallet.publicKey,
       mintPhase.authorization
     ),
+    ...remainingAccountsForMintGuards(
+      mintConfig.pubkey,
+      mintPhaseIx,
+      wallet.publicKey,
+      mintPhase,
+      mintConfig.parsed.maxPerWalletTotal
+    ),
   ];
 
-  if (mintConfig.parsed.outputMintConfig.merkleTree) {
+  if (!mintConfig.parsed.outputMintConfig.randomnessSource.slotHashes) {
+    // the entry is picked and minted later by reveal_mint
     remainingAccounts.push(
-      ...remainingAccountsForMintCnft(
-        mintConfig.parsed.outputMintConfig.merkleTree
+      ...remainingAccountsForPendingMint(
+        mintConfig.pubkey,
+        wallet.publicKey,
+        mintConfig.parsed.outputMintConfig.randomnessSource,
+        options?.vrfRandomnessId
       )
     );
   } else {
-    outputMintKeypair = options?.outputMintKeypair ?? Keypair.generate();
-    remainingAccounts.push(
-      ...remainingAccountsForMintNft(
-        outputMintKeypair.publicKey,
-        wallet.publicKey,
-        mintConfig.parsed.outputMintConfig.ruleset
-      ),
-      ...remainingAccountsForCollection(
-        mintConfig.pubkey,
-        mintConfig.parsed.authority,
-        mintConfig.parsed.outputMintConfig.collection
-      ),
-      ...remainingAccountsForRelease(
-        mintConfig.pubkey,
-        outputMintKeypair.publicKey,
-        mintConfig.parsed.outputMintConfig.releaseAuthority
-      )
+    const [outputMintAccounts, keypair] = remainingAccountsForOutputMint(
+      mintConfig,
+      mintPhaseIx,
+      wallet.publicKey,
+      options
     );
+    remainingAccounts.push(...outputMintAccounts);
+    outputMintKeypair = keypair;
   }
   tx.add(
     await mintGeneratorProgram(connection, wallet)
       .methods.mint({
         mintPhaseIx,
+        merkleMintEntry: options?.merkleMintEntry ?? null,
       })
       .accountsStrict({
         mintConfig: mintConfig.pubkey,
@@ -137,6 +151,75 @@ export const mintSync = async (
   return [tx, outputMintKeypair];
 };
 
+export const revealMint = async (
+  connection: Connection,
+  wallet: Wallet,
+  mintConfigId: PublicKey,
+  user: PublicKey,
+  options?: {
+    outputMintKeypair?: Keypair;
+    computeLimit?: number;
+    merkleMintEntry?: IdlTypes["MerkleMintEntry"];
+    mintEntryPageIndex?: number;
+  }
+): Promise<[Transaction, Keypair | null]> => {
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    mintConfigId,
+    "mintConfig"
+  );
+  const pendingMint = await fetchIdlAccount(
+    connection,
+    findPendingMintId(mintConfigId, user),
+    "pendingMint"
+  );
+  return revealMintSync(connection, wallet, mintConfig, pendingMint, options);
+};
+
+export const revealMintSync = async (
+  connection: Connection,
+  wallet: Wallet,
+  mintConfig: IdlAccountData<"mintConfig">,
+  pendingMint: IdlAccountData<"pendingMint">,
+  options?: {
+    outputMintKeypair?: Keypair;
+    computeLimit?: number;
+    merkleMintEntry?: IdlTypes["MerkleMintEntry"];
+    mintEntryPageIndex?: number;
+  }
+): Promise<[Transaction, Keypair | null]> => {
+  const tx = new Transaction();
+  tx.add(
+    ComputeBudgetProgram.setComputeUnitLimit({
+      units: options?.computeLimit ?? 2_000_000,
+    })
+  );
+  const [remainingAccounts, outputMintKeypair] = remainingAccountsForOutputMint(
+    mintConfig,
+    pendingMint.parsed.mintPhaseIndex,
+    pendingMint.parsed.user,
+    options
+  );
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.revealMint({
+        merkleMintEntry: options?.merkleMintEntry ?? null,
+      })
+      .accountsStrict({
+        mintConfig: mintConfig.pubkey,
+        pendingMint: pendingMint.pubkey,
+        user: pendingMint.parsed.user,
+        payer: pendingMint.parsed.payer,
+        collector: mintConfig.parsed.authority,
+        recentSlothashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(remainingAccounts)
+      .instruction()
+  );
+  return [tx, outputMintKeypair];
+};
+
 export const releaseOutputMint = async (
   connection: Connection,
   wallet: Wallet,
@@ -188,3 +271,54 @@ export const releaseOutputMint = async (
   );
   return tx;
 };
+
+// accounts to pick the entry and mint the token for the phase - mint entry pages come first when using paged storage
+const remainingAccountsForOutputMint = (
+  mintConfig: IdlAccountData<"mintConfig">,
+  mintPhaseIx: number,
+  user: PublicKey,
+  options?: {
+    outputMintKeypair?: Keypair;
+    mintEntryPageIndex?: number;
+  }
+): [AccountMeta[], Keypair | null] => {
+  const outputMintConfig = phaseOutputMintConfig(
+    mintConfig.parsed,
+    mintPhaseIx
+  );
+  const remainingAccounts = remainingAccountsForMintEntryPage(
+    mintConfig.pubkey,
+    mintConfig.parsed.entryStorage,
+    options?.mintEntryPageIndex
+  );
+  if (outputMintConfig.merkleTree) {
+    remainingAccounts.push(
+      ...remainingAccountsForMintCnft(outputMintConfig.merkleTree)
+    );
+    return [remainingAccounts, null];
+  }
+  const outputMintKeypair = options?.outputMintKeypair ?? Keypair.generate();
+  remainingAccounts.push(
+    ...remainingAccountsForMintNft(
+      outputMintKeypair.publicKey,
+      user,
+      outputMintConfig.ruleset
+    ),
+    ...remainingAccountsForCollection(
+      mintConfig.pubkey,
+      mintConfig.parsed.authority,
+      outputMintConfig.collection
+    ),
+    ...remainingAccountsForHiddenMint(
+      mintConfig.pubkey,
+      outputMintKeypair.publicKey,
+      outputMintConfig.hiddenSettings
+    ),
+    ...remainingAccountsForRelease(
+      mintConfig.pubkey,
+      outputMintKeypair.publicKey,
+      outputMintConfig.releaseAuthority
+    )
+  );
+  return [remainingAccounts, outputMintKeypair];
+};
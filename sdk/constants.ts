@@ -20,8 +20,16 @@ export const STRING_PREFIX_LENGTH = 4;
 export const MAX_NAME_LENGTH = 32;
 export const MAX_SYMBOL_LENGTH = 10;
 export const MAX_URI_LENGTH = 200;
+export const MINT_ENTRY_WEIGHT_LENGTH = 2;
 export const MINT_ENTRY_SIZE =
-  MAX_NAME_LENGTH + MAX_SYMBOL_LENGTH + MAX_URI_LENGTH;
+  MAX_NAME_LENGTH +
+  MAX_SYMBOL_LENGTH +
+  MAX_URI_LENGTH +
+  MINT_ENTRY_WEIGHT_LENGTH;
+export const MINT_ENTRY_WEIGHT_BLOCK_SIZE = 128;
+export const MINT_ENTRY_WEIGHT_BLOCK_SUM_LENGTH = 8;
+export const MINT_ENTRY_PAGE_CAPACITY = 1000;
+export const PENDING_MINT_REVEAL_SLOTS = 32;
 
 export type IdlAccountData<T extends keyof AllAccountsMap<MintGenerator>> =
   cIdlAccountData<T, MintGenerator>;
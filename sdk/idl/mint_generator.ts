@@ -101,6 +101,32 @@ export type MintGenerator = {
         }
       ];
     },
+    {
+      name: "migrateMintConfig";
+      accounts: [
+        {
+          name: "mintConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
     {
       name: "closeMintConfig";
       accounts: [
@@ -117,6 +143,82 @@ export type MintGenerator = {
       ];
       args: [];
     },
+    {
+      name: "setMintConfigProvenanceHash";
+      accounts: [
+        {
+          name: "mintConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "SetMintConfigProvenanceHashIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "verifyMintConfigProvenanceHash";
+      accounts: [
+        {
+          name: "mintConfig";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "pauseMint";
+      accounts: [
+        {
+          name: "mintConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "resumeMint";
+      accounts: [
+        {
+          name: "mintConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    },
     {
       name: "setMintPhaseAuthorization";
       accounts: [
@@ -210,7 +312,7 @@ export type MintGenerator = {
       ];
     },
     {
-      name: "mint";
+      name: "setMintEntries";
       accounts: [
         {
           name: "mintConfig";
@@ -218,9 +320,9 @@ export type MintGenerator = {
           isSigner: false;
         },
         {
-          name: "user";
+          name: "authority";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
         {
           name: "payer";
@@ -228,14 +330,37 @@ export type MintGenerator = {
           isSigner: true;
         },
         {
-          name: "collector";
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "SetMintEntriesIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "setMintEntryTemplate";
+      accounts: [
+        {
+          name: "mintConfig";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "recentSlothashes";
+          name: "authority";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
         },
         {
           name: "systemProgram";
@@ -247,13 +372,13 @@ export type MintGenerator = {
         {
           name: "ix";
           type: {
-            defined: "MintIx";
+            defined: "SetMintEntryTemplateIx";
           };
         }
       ];
     },
     {
-      name: "releaseOutputMint";
+      name: "removeMintEntries";
       accounts: [
         {
           name: "mintConfig";
@@ -261,259 +386,536 @@ export type MintGenerator = {
           isSigner: false;
         },
         {
-          name: "outputMintPendingRelease";
+          name: "authority";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "user";
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "RemoveMintEntriesIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "truncateSupply";
+      accounts: [
+        {
+          name: "mintConfig";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "outputMintUserTokenAccount";
+          name: "authority";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "outputMint";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
-        },
+        }
+      ];
+      args: [
         {
-          name: "outputMintMetadata";
+          name: "ix";
+          type: {
+            defined: "TruncateSupplyIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "setMintEntryMerkleRoot";
+      accounts: [
+        {
+          name: "mintConfig";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "outputMintEdition";
+          name: "authority";
           isMut: false;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "outputMintUserTokenRecord";
+          name: "payer";
           isMut: true;
-          isSigner: false;
+          isSigner: true;
         },
         {
-          name: "outputMintAuthorizationRules";
+          name: "systemProgram";
           isMut: false;
           isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "SetMintEntryMerkleRootIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "initMintEntryPage";
+      accounts: [
+        {
+          name: "mintEntryPage";
+          isMut: true;
+          isSigner: false;
         },
         {
-          name: "payer";
+          name: "mintConfig";
           isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
           isSigner: true;
         },
         {
-          name: "releaseAuthority";
+          name: "payer";
           isMut: true;
           isSigner: true;
         },
         {
-          name: "collector";
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "InitMintEntryPageIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "closeMintEntryPage";
+      accounts: [
+        {
+          name: "mintEntryPage";
           isMut: true;
           isSigner: false;
         },
         {
-          name: "systemProgram";
+          name: "mintConfig";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "instructions";
-          isMut: false;
+          name: "authority";
+          isMut: true;
+          isSigner: true;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "mint";
+      accounts: [
+        {
+          name: "mintConfig";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenProgram";
+          name: "user";
           isMut: false;
           isSigner: false;
         },
         {
-          name: "authorizationRulesProgram";
-          isMut: false;
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "collector";
+          isMut: true;
           isSigner: false;
         },
         {
-          name: "tokenMetadataProgram";
+          name: "recentSlothashes";
           isMut: false;
           isSigner: false;
-        }
-      ];
-      args: [];
-    }
-  ];
-  accounts: [
-    {
-      name: "mintConfig";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "authority";
-            type: "publicKey";
-          },
-          {
-            name: "name";
-            type: "string";
-          },
-          {
-            name: "supply";
-            type: "u64";
-          },
-          {
-            name: "count";
-            type: "u64";
-          },
-          {
-            name: "outputMintConfig";
-            type: {
-              defined: "OutputMintConfig";
-            };
-          },
-          {
-            name: "mintPhases";
-            type: {
-              vec: {
-                defined: "MintPhase";
-              };
-            };
-          },
-          {
-            name: "metadata";
-            type: "string";
-          }
-        ];
-      };
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "MintIx";
+          };
+        }
+      ];
     },
     {
-      name: "mintPhaseAuthorization";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "mintConfig";
-            type: "publicKey";
-          },
-          {
-            name: "mintPhaseIndex";
-            type: "u8";
-          },
-          {
-            name: "user";
-            type: "publicKey";
-          },
-          {
-            name: "count";
-            type: "u64";
-          },
-          {
-            name: "remaining";
-            type: {
-              option: "u64";
-            };
-          }
-        ];
-      };
+      name: "mintAuto";
+      accounts: [
+        {
+          name: "mintConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "collector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recentSlothashes";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "MintAutoIx";
+          };
+        }
+      ];
     },
     {
-      name: "outputMintPendingRelease";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "bump";
-            type: "u8";
-          },
-          {
-            name: "mintConfig";
-            type: "publicKey";
-          },
-          {
-            name: "mint";
-            type: "publicKey";
-          },
-          {
-            name: "holder";
-            type: "publicKey";
-          }
-        ];
-      };
-    }
-  ];
-  types: [
-    {
-      name: "SetMintPhaseAuthorizationIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "remaining";
-            type: {
-              option: "u64";
-            };
-          },
-          {
-            name: "user";
-            type: "publicKey";
-          },
-          {
-            name: "mintPhaseIx";
-            type: "u8";
-          }
-        ];
-      };
+      name: "revealMint";
+      accounts: [
+        {
+          name: "mintConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "pendingMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "collector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "recentSlothashes";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "RevealMintIx";
+          };
+        }
+      ];
     },
     {
-      name: "MintIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "mintPhaseIx";
-            type: "u8";
-          }
-        ];
-      };
+      name: "expirePendingMint";
+      accounts: [
+        {
+          name: "mintConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "pendingMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "collector";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      name: "SetMintEntryIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "index";
-            type: "u64";
-          },
-          {
-            name: "name";
-            type: "string";
-          },
-          {
-            name: "symbol";
-            type: "string";
-          },
-          {
-            name: "uri";
-            type: "string";
-          }
-        ];
-      };
+      name: "vrfCallback";
+      accounts: [
+        {
+          name: "vrfRandomness";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "pendingMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "mintConfig";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
     },
     {
-      name: "InitMintConfigIx";
+      name: "revealOutputMint";
+      accounts: [
+        {
+          name: "mintConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "hiddenMint";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "outputMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "outputMintMetadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "outputMintEdition";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "outputMintAuthorizationRules";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "revealAuthority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authorizationRulesProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "releaseOutputMint";
+      accounts: [
+        {
+          name: "mintConfig";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "outputMintPendingRelease";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "user";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "outputMintUserTokenAccount";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "outputMint";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "outputMintMetadata";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "outputMintEdition";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "outputMintUserTokenRecord";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "outputMintAuthorizationRules";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "releaseAuthority";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "collector";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "instructions";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authorizationRulesProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "tokenMetadataProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    },
+    {
+      name: "getMintEntry";
+      accounts: [
+        {
+          name: "mintConfig";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "GetMintEntryIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "getActivePhases";
+      accounts: [
+        {
+          name: "mintConfig";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "ix";
+          type: {
+            defined: "GetActivePhasesIx";
+          };
+        }
+      ];
+    },
+    {
+      name: "getRemainingSupply";
+      accounts: [
+        {
+          name: "mintConfig";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [];
+    }
+  ];
+  accounts: [
+    {
+      name: "mintConfig";
       type: {
         kind: "struct";
         fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
           {
             name: "authority";
             type: "publicKey";
@@ -522,6 +924,14 @@ export type MintGenerator = {
             name: "name";
             type: "string";
           },
+          {
+            name: "supply";
+            type: "u64";
+          },
+          {
+            name: "count";
+            type: "u64";
+          },
           {
             name: "outputMintConfig";
             type: {
@@ -539,746 +949,2813 @@ export type MintGenerator = {
           {
             name: "metadata";
             type: "string";
-          }
-        ];
-      };
-    },
-    {
-      name: "SetMintConfigMetadataIx";
-      type: {
-        kind: "struct";
-        fields: [
+          },
           {
-            name: "metadata";
-            type: "string";
-          }
-        ];
-      };
-    },
-    {
-      name: "UpdateMintConfigIx";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "authority";
-            type: "publicKey";
+            name: "pendingReveals";
+            type: "u64";
           },
           {
-            name: "outputMintConfig";
-            type: {
-              defined: "OutputMintConfig";
-            };
+            name: "totalWeight";
+            type: "u64";
           },
           {
-            name: "mintPhases";
+            name: "provenanceHash";
             type: {
-              vec: {
-                defined: "MintPhase";
+              option: {
+                array: ["u8", 32];
               };
             };
           },
           {
-            name: "metadata";
-            type: "string";
-          }
-        ];
-      };
-    },
-    {
-      name: "OutputMintConfig";
-      type: {
-        kind: "struct";
-        fields: [
-          {
-            name: "sellerFeeBasisPoints";
-            type: "u16";
-          },
-          {
-            name: "tokenStandard";
+            name: "entryStorage";
             type: {
-              defined: "TokenStandard";
+              defined: "MintEntryStorage";
             };
           },
           {
-            name: "collection";
+            name: "maxPerWalletTotal";
             type: {
-              option: "publicKey";
+              option: "u64";
             };
           },
           {
-            name: "ruleset";
+            name: "paused";
+            type: "bool";
+          },
+          {
+            name: "pauseAuthority";
             type: {
               option: "publicKey";
             };
           },
           {
-            name: "creators";
+            name: "phaseSelection";
             type: {
-              vec: {
-                defined: "Creator";
-              };
+              defined: "MintPhaseSelection";
             };
           },
           {
-            name: "merkleTree";
+            name: "botTaxLamports";
             type: {
-              option: "publicKey";
+              option: "u64";
             };
           },
           {
-            name: "releaseAuthority";
+            name: "weightBlockSums";
             type: {
-              option: "publicKey";
+              vec: "u64";
             };
           }
         ];
       };
     },
     {
-      name: "Creator";
+      name: "mintPhaseAuthorization";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "address";
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "mintConfig";
             type: "publicKey";
           },
           {
-            name: "share";
+            name: "mintPhaseIndex";
             type: "u8";
+          },
+          {
+            name: "user";
+            type: "publicKey";
+          },
+          {
+            name: "count";
+            type: "u64";
+          },
+          {
+            name: "remaining";
+            type: {
+              option: "u64";
+            };
           }
         ];
       };
     },
     {
-      name: "MintPhase";
+      name: "outputMintPendingRelease";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "startCondition";
-            type: {
-              option: {
-                defined: "MintPhaseStartEndCondition";
-              };
-            };
+            name: "bump";
+            type: "u8";
           },
           {
-            name: "endCondition";
-            type: {
-              option: {
-                defined: "MintPhaseStartEndCondition";
-              };
-            };
+            name: "mintConfig";
+            type: "publicKey";
           },
           {
-            name: "tokenChecks";
-            type: {
-              vec: {
-                defined: "MintPhaseTokenCheck";
-              };
-            };
+            name: "mint";
+            type: "publicKey";
           },
           {
-            name: "authorization";
-            type: {
-              option: {
-                defined: "MintPhaseAuthorizationCheck";
-              };
-            };
+            name: "holder";
+            type: "publicKey";
           },
           {
-            name: "metadata";
-            type: "string";
+            name: "releaseAuthority";
+            type: "publicKey";
           }
         ];
       };
     },
     {
-      name: "MintPhaseStartEndCondition";
+      name: "mintCounter";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "timeSeconds";
-            type: {
-              option: "i64";
-            };
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "mintConfig";
+            type: "publicKey";
+          },
+          {
+            name: "mintPhaseIndex";
+            type: "u8";
+          },
+          {
+            name: "user";
+            type: "publicKey";
           },
           {
             name: "count";
-            type: {
-              option: "u64";
-            };
+            type: "u64";
           }
         ];
       };
     },
     {
-      name: "MintPhaseTokenCheck";
+      name: "mintCooldown";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "addressKind";
-            type: {
-              defined: "MintPhaseTokenCheckAddressKind";
-            };
+            name: "bump";
+            type: "u8";
           },
           {
-            name: "address";
+            name: "mintConfig";
             type: "publicKey";
           },
           {
-            name: "amount";
-            type: "u64";
+            name: "mintPhaseIndex";
+            type: "u8";
           },
           {
-            name: "transferTarget";
-            type: {
-              option: "publicKey";
-            };
+            name: "user";
+            type: "publicKey";
           },
           {
-            name: "mode";
-            type: {
-              defined: "MintPhaseTokenCheckMode";
-            };
+            name: "lastMintSeconds";
+            type: "i64";
           }
         ];
       };
     },
     {
-      name: "MintPhaseAuthorizationCheck";
+      name: "mintWalletCounter";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "mode";
-            type: {
-              defined: "MintPhaseAuthorizationMode";
-            };
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "mintConfig";
+            type: "publicKey";
+          },
+          {
+            name: "user";
+            type: "publicKey";
+          },
+          {
+            name: "count";
+            type: "u64";
           }
         ];
       };
     },
     {
-      name: "MintEntry";
+      name: "pendingMint";
       type: {
         kind: "struct";
         fields: [
           {
-            name: "name";
-            type: "string";
+            name: "bump";
+            type: "u8";
           },
           {
-            name: "symbol";
-            type: "string";
+            name: "mintConfig";
+            type: "publicKey";
           },
           {
-            name: "uri";
-            type: "string";
-          }
-        ];
-      };
-    },
-    {
-      name: "TokenStandard";
-      type: {
-        kind: "enum";
-        variants: [
+            name: "user";
+            type: "publicKey";
+          },
           {
-            name: "NonFungible";
+            name: "payer";
+            type: "publicKey";
           },
           {
-            name: "FungibleAsset";
+            name: "mintPhaseIndex";
+            type: "u8";
           },
           {
-            name: "Fungible";
+            name: "commitSlot";
+            type: "u64";
           },
           {
-            name: "NonFungibleEdition";
+            name: "commitHash";
+            type: {
+              array: ["u8", 32];
+            };
           },
           {
-            name: "ProgrammableNonFungible";
+            name: "vrfRandomness";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "randomness";
+            type: {
+              option: {
+                array: ["u8", 32];
+              };
+            };
+          },
+          {
+            name: "revealSlot";
+            type: "u64";
           }
         ];
       };
     },
     {
-      name: "MintPhaseTokenCheckAddressKind";
+      name: "mintEntryPage";
       type: {
-        kind: "enum";
-        variants: [
+        kind: "struct";
+        fields: [
           {
-            name: "Mint";
+            name: "bump";
+            type: "u8";
           },
           {
-            name: "Collection";
+            name: "mintConfig";
+            type: "publicKey";
           },
           {
-            name: "Creator";
+            name: "pageIndex";
+            type: "u32";
+          },
+          {
+            name: "supply";
+            type: "u32";
+          },
+          {
+            name: "count";
+            type: "u32";
           }
         ];
       };
     },
     {
-      name: "MintPhaseTokenCheckMode";
+      name: "hiddenMint";
       type: {
-        kind: "enum";
-        variants: [
+        kind: "struct";
+        fields: [
           {
-            name: "Check";
+            name: "bump";
+            type: "u8";
           },
           {
-            name: "Transfer";
+            name: "mintConfig";
+            type: "publicKey";
           },
           {
-            name: "Burn";
+            name: "mint";
+            type: "publicKey";
+          },
+          {
+            name: "payer";
+            type: "publicKey";
+          },
+          {
+            name: "mintEntry";
+            type: {
+              defined: "MintEntry";
+            };
+          },
+          {
+            name: "mintPhaseIndex";
+            type: "u8";
           }
         ];
       };
-    },
+    }
+  ];
+  types: [
     {
-      name: "MintPhaseAuthorizationMode";
+      name: "SetMintPhaseAuthorizationIx";
       type: {
-        kind: "enum";
-        variants: [
+        kind: "struct";
+        fields: [
           {
-            name: "DefaultDisallowed";
+            name: "remaining";
+            type: {
+              option: "u64";
+            };
           },
           {
-            name: "DefaultAllowed";
+            name: "user";
+            type: "publicKey";
+          },
+          {
+            name: "mintPhaseIx";
+            type: "u8";
           }
         ];
       };
-    }
-  ];
-  errors: [
-    {
-      code: 6000;
-      name: "InvalidAuthority";
-      msg: "Invalid authority";
-    },
-    {
-      code: 6001;
-      name: "InvalidMintPhaseAuthorization";
-      msg: "Invalid mint phase authorization";
     },
     {
-      code: 6002;
-      name: "InvalidIndex";
-      msg: "Invalid mint entry index";
+      name: "InitMintEntryPageIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "pageIndex";
+            type: "u32";
+          }
+        ];
+      };
     },
     {
-      code: 6003;
-      name: "InvalidProgramId";
-      msg: "Invalid program id";
+      name: "MintIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mintPhaseIx";
+            type: "u8";
+          },
+          {
+            name: "merkleMintEntry";
+            type: {
+              option: {
+                defined: "MerkleMintEntry";
+              };
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6010;
-      name: "TooManyCreators";
-      msg: "Too many creators";
+      name: "MintAutoIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "merkleMintEntry";
+            type: {
+              option: {
+                defined: "MerkleMintEntry";
+              };
+            };
+          },
+          {
+            name: "phaseAccounts";
+            type: {
+              vec: {
+                defined: "MintAutoPhaseAccounts";
+              };
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6011;
-      name: "InvalidMintConfigId";
-      msg: "Invalid mint config id";
+      name: "MintAutoPhaseAccounts";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mintPhaseIndex";
+            type: "u8";
+          },
+          {
+            name: "accountsLength";
+            type: "u8";
+          }
+        ];
+      };
     },
     {
-      code: 6012;
-      name: "InvalidTokenStandard";
-      msg: "Invalid token standard";
+      name: "MintAutoResult";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mintPhaseIndex";
+            type: "u8";
+          },
+          {
+            name: "minted";
+            type: "bool";
+          }
+        ];
+      };
     },
     {
-      code: 6013;
-      name: "ProgrammableAndMerkleTree";
-      msg: "Cannot mint with programmably nft and merkle tree";
+      name: "RemoveMintEntriesIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "startIndex";
+            type: "u64";
+          },
+          {
+            name: "length";
+            type: "u64";
+          }
+        ];
+      };
     },
     {
-      code: 6020;
-      name: "MintingAlreadyStarted";
-      msg: "Minting already started";
+      name: "RevealMintIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "merkleMintEntry";
+            type: {
+              option: {
+                defined: "MerkleMintEntry";
+              };
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6021;
-      name: "InvalidPhase";
-      msg: "Invalid phase";
+      name: "SetMintEntriesIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "startIndex";
+            type: "u64";
+          },
+          {
+            name: "entries";
+            type: {
+              vec: {
+                defined: "MintEntryIx";
+              };
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6022;
-      name: "PhaseNotActive";
-      msg: "Phase not active";
+      name: "MintEntryIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "symbol";
+            type: "string";
+          },
+          {
+            name: "uri";
+            type: "string";
+          },
+          {
+            name: "weight";
+            type: {
+              option: "u16";
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6023;
-      name: "NotTokensRemaining";
-      msg: "No tokens remaining";
+      name: "SetMintEntryIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "index";
+            type: "u64";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "symbol";
+            type: "string";
+          },
+          {
+            name: "uri";
+            type: "string";
+          },
+          {
+            name: "weight";
+            type: {
+              option: "u16";
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6030;
-      name: "HolderNotSigner";
-      msg: "Holder must be signer";
+      name: "SetMintEntryMerkleRootIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "root";
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "supply";
+            type: "u64";
+          }
+        ];
+      };
     },
     {
-      code: 6031;
-      name: "InvalidTokenCheckHolderTokenAccount";
-      msg: "Invalid token check holder token account";
+      name: "SetMintEntryTemplateIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "template";
+            type: {
+              defined: "MintEntryTemplate";
+            };
+          },
+          {
+            name: "supply";
+            type: "u64";
+          }
+        ];
+      };
     },
     {
-      code: 6032;
-      name: "InvalidTokenCheckTransferTarget";
-      msg: "Invalid token check transfer target";
+      name: "TruncateSupplyIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "supply";
+            type: "u64";
+          }
+        ];
+      };
     },
     {
-      code: 6033;
-      name: "InvalidTokenCheck";
-      msg: "Invalid token check";
+      name: "InitMintConfigIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "outputMintConfig";
+            type: {
+              defined: "OutputMintConfig";
+            };
+          },
+          {
+            name: "mintPhases";
+            type: {
+              vec: {
+                defined: "MintPhase";
+              };
+            };
+          },
+          {
+            name: "metadata";
+            type: "string";
+          },
+          {
+            name: "maxPerWalletTotal";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "pauseAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "phaseSelection";
+            type: {
+              defined: "MintPhaseSelection";
+            };
+          },
+          {
+            name: "botTaxLamports";
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6034;
-      name: "InvalidMintMetadata";
-      msg: "Invalid mint metadata";
+      name: "SetMintConfigMetadataIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "metadata";
+            type: "string";
+          }
+        ];
+      };
     },
     {
-      code: 6035;
-      name: "InvalidMintMetadataOwner";
-      msg: "Invalid mint metadata owner";
+      name: "SetMintConfigProvenanceHashIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "provenanceHash";
+            type: {
+              array: ["u8", 32];
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6040;
-      name: "MintPhaseAuthorizationsUsed";
-      msg: "Mint phase authorizations used";
+      name: "UpdateMintConfigIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "outputMintConfig";
+            type: {
+              defined: "OutputMintConfig";
+            };
+          },
+          {
+            name: "mintPhases";
+            type: {
+              vec: {
+                defined: "MintPhase";
+              };
+            };
+          },
+          {
+            name: "metadata";
+            type: "string";
+          },
+          {
+            name: "maxPerWalletTotal";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "pauseAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "phaseSelection";
+            type: {
+              defined: "MintPhaseSelection";
+            };
+          },
+          {
+            name: "botTaxLamports";
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6041;
-      name: "IncorrectAuthorizationHolder";
-      msg: "IncorrectAuthorizationHolder";
+      name: "OutputMintConfig";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "sellerFeeBasisPoints";
+            type: "u16";
+          },
+          {
+            name: "tokenStandard";
+            type: {
+              defined: "TokenStandard";
+            };
+          },
+          {
+            name: "collection";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "ruleset";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "creators";
+            type: {
+              vec: {
+                defined: "Creator";
+              };
+            };
+          },
+          {
+            name: "merkleTree";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "releaseAuthority";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "randomnessSource";
+            type: {
+              defined: "RandomnessSource";
+            };
+          },
+          {
+            name: "selectionMode";
+            type: {
+              defined: "MintSelectionMode";
+            };
+          },
+          {
+            name: "hiddenSettings";
+            type: {
+              option: {
+                defined: "HiddenSettings";
+              };
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6050;
-      name: "ReleaseTimeInvalid";
-      msg: "Release time invalid";
+      name: "HiddenSettings";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "uri";
+            type: "string";
+          },
+          {
+            name: "revealAuthority";
+            type: {
+              option: "publicKey";
+            };
+          }
+        ];
+      };
     },
     {
-      code: 6051;
-      name: "InvalidOutputMintsPendingRelease";
-      msg: "Invalid output mints pending release";
-    }
-  ];
-};
-
-export const IDL: MintGenerator = {
-  version: "0.1.0",
-  name: "mint_generator",
-  instructions: [
-    {
-      name: "initMintConfig",
-      accounts: [
-        {
-          name: "mintConfig",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "InitMintConfigIx",
+      name: "VrfRandomness";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "discriminator";
+            type: {
+              array: ["u8", 8];
+            };
           },
-        },
-      ],
+          {
+            name: "requester";
+            type: "publicKey";
+          },
+          {
+            name: "seed";
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "fulfilled";
+            type: "bool";
+          },
+          {
+            name: "randomness";
+            type: {
+              array: ["u8", 32];
+            };
+          }
+        ];
+      };
     },
     {
-      name: "updateMintConfig",
-      accounts: [
-        {
-          name: "mintConfig",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "UpdateMintConfigIx",
+      name: "Creator";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "address";
+            type: "publicKey";
           },
-        },
-      ],
+          {
+            name: "share";
+            type: "u8";
+          }
+        ];
+      };
     },
     {
-      name: "setMintConfigMetadata",
-      accounts: [
-        {
-          name: "mintConfig",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "SetMintConfigMetadataIx",
+      name: "MintPhase";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "startCondition";
+            type: {
+              option: {
+                defined: "MintPhaseCondition";
+              };
+            };
           },
-        },
-      ],
+          {
+            name: "endCondition";
+            type: {
+              option: {
+                defined: "MintPhaseCondition";
+              };
+            };
+          },
+          {
+            name: "tokenChecks";
+            type: {
+              vec: {
+                defined: "MintPhaseTokenCheck";
+              };
+            };
+          },
+          {
+            name: "authorization";
+            type: {
+              option: {
+                defined: "MintPhaseAuthorizationCheck";
+              };
+            };
+          },
+          {
+            name: "metadata";
+            type: "string";
+          },
+          {
+            name: "priceCurve";
+            type: {
+              option: {
+                defined: "MintPhasePriceCurve";
+              };
+            };
+          },
+          {
+            name: "maxPerWallet";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "maxSupply";
+            type: {
+              option: "u64";
+            };
+          },
+          {
+            name: "minted";
+            type: "u64";
+          },
+          {
+            name: "outputOverrides";
+            type: {
+              option: {
+                defined: "MintPhaseOutputOverrides";
+              };
+            };
+          },
+          {
+            name: "cooldownSeconds";
+            type: {
+              option: "i64";
+            };
+          }
+        ];
+      };
     },
     {
-      name: "closeMintConfig",
-      accounts: [
-        {
-          name: "mintConfig",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-      ],
-      args: [],
+      name: "MintPhaseOutputOverrides";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "collection";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "creators";
+            type: {
+              option: {
+                vec: {
+                  defined: "Creator";
+                };
+              };
+            };
+          },
+          {
+            name: "sellerFeeBasisPoints";
+            type: {
+              option: "u16";
+            };
+          },
+          {
+            name: "tokenStandard";
+            type: {
+              option: {
+                defined: "TokenStandard";
+              };
+            };
+          },
+          {
+            name: "ruleset";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "releaseAuthority";
+            type: {
+              option: "publicKey";
+            };
+          }
+        ];
+      };
     },
     {
-      name: "setMintPhaseAuthorization",
-      accounts: [
-        {
-          name: "mintPhaseAuthorization",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "mintConfig",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "SetMintPhaseAuthorizationIx",
+      name: "MintPhasePriceTier";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "upToCount";
+            type: "u64";
           },
-        },
-      ],
+          {
+            name: "price";
+            type: "u64";
+          }
+        ];
+      };
     },
     {
-      name: "closeMintPhaseAuthorization",
-      accounts: [
-        {
-          name: "mintPhaseAuthorization",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "mintConfig",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-      ],
-      args: [],
+      name: "MintPhaseTokenCheck";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "addressKind";
+            type: {
+              defined: "MintPhaseTokenCheckAddressKind";
+            };
+          },
+          {
+            name: "address";
+            type: "publicKey";
+          },
+          {
+            name: "amount";
+            type: "u64";
+          },
+          {
+            name: "transferTarget";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "mode";
+            type: {
+              defined: "MintPhaseTokenCheckMode";
+            };
+          }
+        ];
+      };
     },
     {
-      name: "setMintEntry",
-      accounts: [
-        {
-          name: "mintConfig",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "authority",
-          isMut: false,
-          isSigner: true,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "SetMintEntryIx",
+      name: "MintPhaseAuthorizationCheck";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mode";
+            type: {
+              defined: "MintPhaseAuthorizationMode";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "MintEntry";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "name";
+            type: "string";
           },
-        },
-      ],
+          {
+            name: "symbol";
+            type: "string";
+          },
+          {
+            name: "uri";
+            type: "string";
+          },
+          {
+            name: "weight";
+            type: "u16";
+          }
+        ];
+      };
     },
     {
-      name: "mint",
-      accounts: [
-        {
-          name: "mintConfig",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "user",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "collector",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "recentSlothashes",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "systemProgram",
-          isMut: false,
-          isSigner: false,
-        },
-      ],
-      args: [
-        {
-          name: "ix",
-          type: {
-            defined: "MintIx",
+      name: "MerkleMintEntry";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "index";
+            type: "u64";
           },
-        },
-      ],
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "symbol";
+            type: "string";
+          },
+          {
+            name: "uri";
+            type: "string";
+          },
+          {
+            name: "proof";
+            type: {
+              vec: {
+                array: ["u8", 32];
+              };
+            };
+          }
+        ];
+      };
     },
     {
-      name: "releaseOutputMint",
-      accounts: [
-        {
-          name: "mintConfig",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "outputMintPendingRelease",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "user",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "outputMintUserTokenAccount",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "outputMint",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "outputMintMetadata",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "outputMintEdition",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "outputMintUserTokenRecord",
-          isMut: true,
-          isSigner: false,
-        },
-        {
-          name: "outputMintAuthorizationRules",
-          isMut: false,
-          isSigner: false,
-        },
-        {
-          name: "payer",
-          isMut: true,
-          isSigner: true,
-        },
-        {
-          name: "releaseAuthority",
-          isMut: true,
+      name: "MintEntryTemplate";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "namePrefix";
+            type: "string";
+          },
+          {
+            name: "symbol";
+            type: "string";
+          },
+          {
+            name: "uriPrefix";
+            type: "string";
+          },
+          {
+            name: "uriSuffix";
+            type: "string";
+          },
+          {
+            name: "indexOffset";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "MintConfigV0";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "authority";
+            type: "publicKey";
+          },
+          {
+            name: "name";
+            type: "string";
+          },
+          {
+            name: "supply";
+            type: "u64";
+          },
+          {
+            name: "count";
+            type: "u64";
+          },
+          {
+            name: "outputMintConfig";
+            type: {
+              defined: "OutputMintConfigV0";
+            };
+          },
+          {
+            name: "mintPhases";
+            type: {
+              vec: {
+                defined: "MintPhaseV0";
+              };
+            };
+          },
+          {
+            name: "metadata";
+            type: "string";
+          }
+        ];
+      };
+    },
+    {
+      name: "OutputMintConfigV0";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "sellerFeeBasisPoints";
+            type: "u16";
+          },
+          {
+            name: "tokenStandard";
+            type: {
+              defined: "TokenStandard";
+            };
+          },
+          {
+            name: "collection";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "ruleset";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "creators";
+            type: {
+              vec: {
+                defined: "Creator";
+              };
+            };
+          },
+          {
+            name: "merkleTree";
+            type: {
+              option: "publicKey";
+            };
+          },
+          {
+            name: "releaseAuthority";
+            type: {
+              option: "publicKey";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "MintPhaseV0";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "startCondition";
+            type: {
+              option: {
+                defined: "MintPhaseStartEndConditionV0";
+              };
+            };
+          },
+          {
+            name: "endCondition";
+            type: {
+              option: {
+                defined: "MintPhaseStartEndConditionV0";
+              };
+            };
+          },
+          {
+            name: "tokenChecks";
+            type: {
+              vec: {
+                defined: "MintPhaseTokenCheck";
+              };
+            };
+          },
+          {
+            name: "authorization";
+            type: {
+              option: {
+                defined: "MintPhaseAuthorizationCheck";
+              };
+            };
+          },
+          {
+            name: "metadata";
+            type: "string";
+          }
+        ];
+      };
+    },
+    {
+      name: "MintPhaseStartEndConditionV0";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "timeSeconds";
+            type: {
+              option: "i64";
+            };
+          },
+          {
+            name: "count";
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "GetActivePhasesIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "user";
+            type: "publicKey";
+          }
+        ];
+      };
+    },
+    {
+      name: "ActivePhase";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "mintPhaseIndex";
+            type: "u8";
+          },
+          {
+            name: "remaining";
+            type: {
+              option: "u64";
+            };
+          }
+        ];
+      };
+    },
+    {
+      name: "GetMintEntryIx";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "index";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "RemainingSupply";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "supply";
+            type: "u64";
+          },
+          {
+            name: "count";
+            type: "u64";
+          },
+          {
+            name: "pendingReveals";
+            type: "u64";
+          },
+          {
+            name: "available";
+            type: "u64";
+          }
+        ];
+      };
+    },
+    {
+      name: "MintPhaseCondition";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "All";
+            fields: [
+              {
+                name: "conditions";
+                type: {
+                  vec: {
+                    defined: "MintPhaseCondition";
+                  };
+                };
+              }
+            ];
+          },
+          {
+            name: "Any";
+            fields: [
+              {
+                name: "conditions";
+                type: {
+                  vec: {
+                    defined: "MintPhaseCondition";
+                  };
+                };
+              }
+            ];
+          },
+          {
+            name: "Not";
+            fields: [
+              {
+                name: "conditions";
+                type: {
+                  vec: {
+                    defined: "MintPhaseCondition";
+                  };
+                };
+              }
+            ];
+          },
+          {
+            name: "Time";
+            fields: [
+              {
+                name: "seconds";
+                type: "i64";
+              }
+            ];
+          },
+          {
+            name: "Count";
+            fields: [
+              {
+                name: "count";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "Slot";
+            fields: [
+              {
+                name: "slot";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "PhaseSoldOut";
+            fields: [
+              {
+                name: "mintPhaseIndex";
+                type: "u8";
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "MintPhaseSelection";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "First";
+          },
+          {
+            name: "Cheapest";
+          }
+        ];
+      };
+    },
+    {
+      name: "MintSelectionMode";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Random";
+          },
+          {
+            name: "Sequential";
+          }
+        ];
+      };
+    },
+    {
+      name: "RandomnessSource";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "SlotHashes";
+          },
+          {
+            name: "CommitReveal";
+          },
+          {
+            name: "Vrf";
+            fields: [
+              {
+                name: "oracleProgram";
+                type: "publicKey";
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "TokenStandard";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "NonFungible";
+          },
+          {
+            name: "FungibleAsset";
+          },
+          {
+            name: "Fungible";
+          },
+          {
+            name: "NonFungibleEdition";
+          },
+          {
+            name: "ProgrammableNonFungible";
+          }
+        ];
+      };
+    },
+    {
+      name: "MintPhasePriceCurve";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "DutchAuction";
+            fields: [
+              {
+                name: "startSeconds";
+                type: "i64";
+              },
+              {
+                name: "startPrice";
+                type: "u64";
+              },
+              {
+                name: "floorPrice";
+                type: "u64";
+              },
+              {
+                name: "decayPerInterval";
+                type: "u64";
+              },
+              {
+                name: "intervalSeconds";
+                type: "i64";
+              }
+            ];
+          },
+          {
+            name: "Linear";
+            fields: [
+              {
+                name: "startPrice";
+                type: "u64";
+              },
+              {
+                name: "incrementPerMint";
+                type: "u64";
+              }
+            ];
+          },
+          {
+            name: "Tiers";
+            fields: [
+              {
+                name: "tiers";
+                type: {
+                  vec: {
+                    defined: "MintPhasePriceTier";
+                  };
+                };
+              }
+            ];
+          }
+        ];
+      };
+    },
+    {
+      name: "MintPhaseTokenCheckAddressKind";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Mint";
+          },
+          {
+            name: "Collection";
+          },
+          {
+            name: "Creator";
+          }
+        ];
+      };
+    },
+    {
+      name: "MintPhaseTokenCheckMode";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Check";
+          },
+          {
+            name: "Transfer";
+          },
+          {
+            name: "Burn";
+          }
+        ];
+      };
+    },
+    {
+      name: "MintPhaseAuthorizationMode";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "DefaultDisallowed";
+          },
+          {
+            name: "DefaultAllowed";
+          }
+        ];
+      };
+    },
+    {
+      name: "MintEntryStorage";
+      type: {
+        kind: "enum";
+        variants: [
+          {
+            name: "Inline";
+          },
+          {
+            name: "Template";
+            fields: [
+              {
+                name: "template";
+                type: {
+                  defined: "MintEntryTemplate";
+                };
+              }
+            ];
+          },
+          {
+            name: "Paged";
+            fields: [
+              {
+                name: "pageRemaining";
+                type: {
+                  vec: "u32";
+                };
+              }
+            ];
+          },
+          {
+            name: "Merkle";
+            fields: [
+              {
+                name: "root";
+                type: {
+                  array: ["u8", 32];
+                };
+              }
+            ];
+          }
+        ];
+      };
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "InvalidAuthority";
+      msg: "Invalid authority";
+    },
+    {
+      code: 6001;
+      name: "InvalidMintPhaseAuthorization";
+      msg: "Invalid mint phase authorization";
+    },
+    {
+      code: 6002;
+      name: "InvalidIndex";
+      msg: "Invalid mint entry index";
+    },
+    {
+      code: 6003;
+      name: "InvalidProgramId";
+      msg: "Invalid program id";
+    },
+    {
+      code: 6004;
+      name: "InvalidMintEntryWeight";
+      msg: "Invalid mint entry weight";
+    },
+    {
+      code: 6005;
+      name: "MintEntryFieldTooLong";
+      msg: "Mint entry field too long";
+    },
+    {
+      code: 6006;
+      name: "InvalidMintEntryData";
+      msg: "Invalid mint entry data";
+    },
+    {
+      code: 6007;
+      name: "InvalidPendingMint";
+      msg: "Invalid pending mint";
+    },
+    {
+      code: 6008;
+      name: "InvalidHiddenMint";
+      msg: "Invalid hidden mint";
+    },
+    {
+      code: 6009;
+      name: "InvalidPriceCurve";
+      msg: "Invalid price curve";
+    },
+    {
+      code: 6010;
+      name: "TooManyCreators";
+      msg: "Too many creators";
+    },
+    {
+      code: 6011;
+      name: "InvalidMintConfigId";
+      msg: "Invalid mint config id";
+    },
+    {
+      code: 6012;
+      name: "InvalidTokenStandard";
+      msg: "Invalid token standard";
+    },
+    {
+      code: 6013;
+      name: "ProgrammableAndMerkleTree";
+      msg: "Cannot mint with programmably nft and merkle tree";
+    },
+    {
+      code: 6014;
+      name: "SequentialAndRandomnessSource";
+      msg: "Cannot use sequential selection with a deferred randomness source";
+    },
+    {
+      code: 6015;
+      name: "ProvenanceHashSet";
+      msg: "Mint entries are locked by the provenance hash";
+    },
+    {
+      code: 6016;
+      name: "ProvenanceHashNotSet";
+      msg: "Provenance hash not set";
+    },
+    {
+      code: 6017;
+      name: "ProvenanceHashMismatch";
+      msg: "Provenance hash does not match mint entries";
+    },
+    {
+      code: 6018;
+      name: "HiddenAndMerkleTree";
+      msg: "Cannot mint hidden tokens into a merkle tree";
+    },
+    {
+      code: 6019;
+      name: "InvalidHiddenSettings";
+      msg: "Invalid hidden settings";
+    },
+    {
+      code: 6020;
+      name: "MintingAlreadyStarted";
+      msg: "Minting already started";
+    },
+    {
+      code: 6021;
+      name: "InvalidPhase";
+      msg: "Invalid phase";
+    },
+    {
+      code: 6022;
+      name: "PhaseNotActive";
+      msg: "Phase not active";
+    },
+    {
+      code: 6023;
+      name: "NotTokensRemaining";
+      msg: "No tokens remaining";
+    },
+    {
+      code: 6024;
+      name: "RevealTooEarly";
+      msg: "Mint must be revealed in a later slot than it was committed";
+    },
+    {
+      code: 6025;
+      name: "InvalidVrfRandomness";
+      msg: "Invalid vrf randomness account";
+    },
+    {
+      code: 6026;
+      name: "VrfRandomnessNotFulfilled";
+      msg: "Vrf randomness not fulfilled";
+    },
+    {
+      code: 6027;
+      name: "InvalidMerkleMintEntry";
+      msg: "Merkle mint entry does not match the picked index";
+    },
+    {
+      code: 6028;
+      name: "InvalidMerkleProof";
+      msg: "Invalid merkle proof";
+    },
+    {
+      code: 6029;
+      name: "MintLimitReached";
+      msg: "Mint limit per wallet reached";
+    },
+    {
+      code: 6030;
+      name: "HolderNotSigner";
+      msg: "Holder must be signer";
+    },
+    {
+      code: 6031;
+      name: "InvalidTokenCheckHolderTokenAccount";
+      msg: "Invalid token check holder token account";
+    },
+    {
+      code: 6032;
+      name: "InvalidTokenCheckTransferTarget";
+      msg: "Invalid token check transfer target";
+    },
+    {
+      code: 6033;
+      name: "InvalidTokenCheck";
+      msg: "Invalid token check";
+    },
+    {
+      code: 6034;
+      name: "InvalidMintMetadata";
+      msg: "Invalid mint metadata";
+    },
+    {
+      code: 6035;
+      name: "InvalidMintMetadataOwner";
+      msg: "Invalid mint metadata owner";
+    },
+    {
+      code: 6040;
+      name: "MintPhaseAuthorizationsUsed";
+      msg: "Mint phase authorizations used";
+    },
+    {
+      code: 6041;
+      name: "IncorrectAuthorizationHolder";
+      msg: "IncorrectAuthorizationHolder";
+    },
+    {
+      code: 6042;
+      name: "InvalidMintCounter";
+      msg: "Invalid mint counter";
+    },
+    {
+      code: 6043;
+      name: "InvalidMintCooldown";
+      msg: "Invalid mint cooldown";
+    },
+    {
+      code: 6044;
+      name: "UserNotSigner";
+      msg: "User must be signer to mint with per wallet limits or a deferred reveal";
+    },
+    {
+      code: 6050;
+      name: "ReleaseTimeInvalid";
+      msg: "Release time invalid";
+    },
+    {
+      code: 6051;
+      name: "InvalidOutputMintsPendingRelease";
+      msg: "Invalid output mints pending release";
+    },
+    {
+      code: 6060;
+      name: "InvalidMintEntryStorage";
+      msg: "Invalid mint entry storage";
+    },
+    {
+      code: 6061;
+      name: "InvalidMintEntryTemplate";
+      msg: "Invalid mint entry template";
+    },
+    {
+      code: 6062;
+      name: "InvalidMintEntryPage";
+      msg: "Invalid mint entry page";
+    },
+    {
+      code: 6063;
+      name: "MerkleAndRandomnessSource";
+      msg: "Merkle entries picked at random need a deferred randomness source";
+    },
+    {
+      code: 6064;
+      name: "PagedAndRandomnessSource";
+      msg: "Paged entries picked at random need a deferred randomness source";
+    },
+    {
+      code: 6070;
+      name: "InvalidPhaseCondition";
+      msg: "Invalid phase condition";
+    },
+    {
+      code: 6071;
+      name: "PhaseEnded";
+      msg: "Phase ended";
+    },
+    {
+      code: 6072;
+      name: "PhaseSoldOut";
+      msg: "Phase sold out";
+    },
+    {
+      code: 6073;
+      name: "NoActivePhase";
+      msg: "No active phase";
+    },
+    {
+      code: 6074;
+      name: "PhaseNotStarted";
+      msg: "Phase not started";
+    },
+    {
+      code: 6080;
+      name: "MintPaused";
+      msg: "Minting is paused";
+    },
+    {
+      code: 6081;
+      name: "MintCooldownActive";
+      msg: "Mint cooldown has not passed";
+    },
+    {
+      code: 6090;
+      name: "PendingMintExpired";
+      msg: "Pending mint expired and can only be released with expire_pending_mint";
+    },
+    {
+      code: 6091;
+      name: "PendingMintNotExpired";
+      msg: "Pending mint has not expired";
+    },
+    {
+      code: 6092;
+      name: "PendingMintAlreadyFulfilled";
+      msg: "Pending mint randomness already fulfilled";
+    },
+    {
+      code: 6100;
+      name: "InvalidLegacyMintConfig";
+      msg: "Mint config is not a legacy mint config";
+    }
+  ];
+};
+
+export const IDL: MintGenerator = {
+  version: "0.1.0",
+  name: "mint_generator",
+  instructions: [
+    {
+      name: "initMintConfig",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitMintConfigIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "updateMintConfig",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "UpdateMintConfigIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "setMintConfigMetadata",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "SetMintConfigMetadataIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "migrateMintConfig",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "closeMintConfig",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setMintConfigProvenanceHash",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "SetMintConfigProvenanceHashIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "verifyMintConfigProvenanceHash",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "pauseMint",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "resumeMint",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setMintPhaseAuthorization",
+      accounts: [
+        {
+          name: "mintPhaseAuthorization",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintConfig",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "SetMintPhaseAuthorizationIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "closeMintPhaseAuthorization",
+      accounts: [
+        {
+          name: "mintPhaseAuthorization",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintConfig",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "setMintEntry",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "SetMintEntryIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "setMintEntries",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "SetMintEntriesIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "setMintEntryTemplate",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "SetMintEntryTemplateIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "removeMintEntries",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "RemoveMintEntriesIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "truncateSupply",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "TruncateSupplyIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "setMintEntryMerkleRoot",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "SetMintEntryMerkleRootIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "initMintEntryPage",
+      accounts: [
+        {
+          name: "mintEntryPage",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "InitMintEntryPageIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "closeMintEntryPage",
+      accounts: [
+        {
+          name: "mintEntryPage",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintConfig",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: true,
+          isSigner: true,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "mint",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "collector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recentSlothashes",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "MintIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "mintAuto",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "collector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recentSlothashes",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "MintAutoIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "revealMint",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "pendingMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "collector",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "recentSlothashes",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "RevealMintIx",
+          },
+        },
+      ],
+    },
+    {
+      name: "expirePendingMint",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "pendingMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "collector",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "vrfCallback",
+      accounts: [
+        {
+          name: "vrfRandomness",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "pendingMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "mintConfig",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "revealOutputMint",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "hiddenMint",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "outputMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "outputMintMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "outputMintEdition",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "outputMintAuthorizationRules",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "revealAuthority",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authorizationRulesProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "releaseOutputMint",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "outputMintPendingRelease",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "user",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "outputMintUserTokenAccount",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "outputMint",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "outputMintMetadata",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "outputMintEdition",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "outputMintUserTokenRecord",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "outputMintAuthorizationRules",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "releaseAuthority",
+          isMut: true,
           isSigner: true,
         },
         {
@@ -1292,179 +3769,1188 @@ export const IDL: MintGenerator = {
           isSigner: false,
         },
         {
-          name: "instructions",
-          isMut: false,
-          isSigner: false,
+          name: "instructions",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authorizationRulesProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "tokenMetadataProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [],
+    },
+    {
+      name: "getMintEntry",
+      accounts: [
+        {
+          name: "mintConfig",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "ix",
+          type: {
+            defined: "GetMintEntryIx",
+          },
         },
+      ],
+    },
+    {
+      name: "getActivePhases",
+      accounts: [
         {
-          name: "tokenProgram",
+          name: "mintConfig",
           isMut: false,
           isSigner: false,
         },
+      ],
+      args: [
         {
-          name: "authorizationRulesProgram",
-          isMut: false,
-          isSigner: false,
+          name: "ix",
+          type: {
+            defined: "GetActivePhasesIx",
+          },
         },
+      ],
+    },
+    {
+      name: "getRemainingSupply",
+      accounts: [
         {
-          name: "tokenMetadataProgram",
+          name: "mintConfig",
           isMut: false,
           isSigner: false,
         },
       ],
       args: [],
     },
-  ],
-  accounts: [
+  ],
+  accounts: [
+    {
+      name: "mintConfig",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "supply",
+            type: "u64",
+          },
+          {
+            name: "count",
+            type: "u64",
+          },
+          {
+            name: "outputMintConfig",
+            type: {
+              defined: "OutputMintConfig",
+            },
+          },
+          {
+            name: "mintPhases",
+            type: {
+              vec: {
+                defined: "MintPhase",
+              },
+            },
+          },
+          {
+            name: "metadata",
+            type: "string",
+          },
+          {
+            name: "pendingReveals",
+            type: "u64",
+          },
+          {
+            name: "totalWeight",
+            type: "u64",
+          },
+          {
+            name: "provenanceHash",
+            type: {
+              option: {
+                array: ["u8", 32],
+              },
+            },
+          },
+          {
+            name: "entryStorage",
+            type: {
+              defined: "MintEntryStorage",
+            },
+          },
+          {
+            name: "maxPerWalletTotal",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "paused",
+            type: "bool",
+          },
+          {
+            name: "pauseAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "phaseSelection",
+            type: {
+              defined: "MintPhaseSelection",
+            },
+          },
+          {
+            name: "botTaxLamports",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "weightBlockSums",
+            type: {
+              vec: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "mintPhaseAuthorization",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "mintConfig",
+            type: "publicKey",
+          },
+          {
+            name: "mintPhaseIndex",
+            type: "u8",
+          },
+          {
+            name: "user",
+            type: "publicKey",
+          },
+          {
+            name: "count",
+            type: "u64",
+          },
+          {
+            name: "remaining",
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "outputMintPendingRelease",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "mintConfig",
+            type: "publicKey",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "holder",
+            type: "publicKey",
+          },
+          {
+            name: "releaseAuthority",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+    {
+      name: "mintCounter",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "mintConfig",
+            type: "publicKey",
+          },
+          {
+            name: "mintPhaseIndex",
+            type: "u8",
+          },
+          {
+            name: "user",
+            type: "publicKey",
+          },
+          {
+            name: "count",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "mintCooldown",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "mintConfig",
+            type: "publicKey",
+          },
+          {
+            name: "mintPhaseIndex",
+            type: "u8",
+          },
+          {
+            name: "user",
+            type: "publicKey",
+          },
+          {
+            name: "lastMintSeconds",
+            type: "i64",
+          },
+        ],
+      },
+    },
+    {
+      name: "mintWalletCounter",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "mintConfig",
+            type: "publicKey",
+          },
+          {
+            name: "user",
+            type: "publicKey",
+          },
+          {
+            name: "count",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "pendingMint",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "mintConfig",
+            type: "publicKey",
+          },
+          {
+            name: "user",
+            type: "publicKey",
+          },
+          {
+            name: "payer",
+            type: "publicKey",
+          },
+          {
+            name: "mintPhaseIndex",
+            type: "u8",
+          },
+          {
+            name: "commitSlot",
+            type: "u64",
+          },
+          {
+            name: "commitHash",
+            type: {
+              array: ["u8", 32],
+            },
+          },
+          {
+            name: "vrfRandomness",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "randomness",
+            type: {
+              option: {
+                array: ["u8", 32],
+              },
+            },
+          },
+          {
+            name: "revealSlot",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "mintEntryPage",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "mintConfig",
+            type: "publicKey",
+          },
+          {
+            name: "pageIndex",
+            type: "u32",
+          },
+          {
+            name: "supply",
+            type: "u32",
+          },
+          {
+            name: "count",
+            type: "u32",
+          },
+        ],
+      },
+    },
+    {
+      name: "hiddenMint",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "mintConfig",
+            type: "publicKey",
+          },
+          {
+            name: "mint",
+            type: "publicKey",
+          },
+          {
+            name: "payer",
+            type: "publicKey",
+          },
+          {
+            name: "mintEntry",
+            type: {
+              defined: "MintEntry",
+            },
+          },
+          {
+            name: "mintPhaseIndex",
+            type: "u8",
+          },
+        ],
+      },
+    },
+  ],
+  types: [
+    {
+      name: "SetMintPhaseAuthorizationIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "remaining",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "user",
+            type: "publicKey",
+          },
+          {
+            name: "mintPhaseIx",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "InitMintEntryPageIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "pageIndex",
+            type: "u32",
+          },
+        ],
+      },
+    },
+    {
+      name: "MintIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mintPhaseIx",
+            type: "u8",
+          },
+          {
+            name: "merkleMintEntry",
+            type: {
+              option: {
+                defined: "MerkleMintEntry",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "MintAutoIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "merkleMintEntry",
+            type: {
+              option: {
+                defined: "MerkleMintEntry",
+              },
+            },
+          },
+          {
+            name: "phaseAccounts",
+            type: {
+              vec: {
+                defined: "MintAutoPhaseAccounts",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "MintAutoPhaseAccounts",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mintPhaseIndex",
+            type: "u8",
+          },
+          {
+            name: "accountsLength",
+            type: "u8",
+          },
+        ],
+      },
+    },
+    {
+      name: "MintAutoResult",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "mintPhaseIndex",
+            type: "u8",
+          },
+          {
+            name: "minted",
+            type: "bool",
+          },
+        ],
+      },
+    },
+    {
+      name: "RemoveMintEntriesIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "startIndex",
+            type: "u64",
+          },
+          {
+            name: "length",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "RevealMintIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "merkleMintEntry",
+            type: {
+              option: {
+                defined: "MerkleMintEntry",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SetMintEntriesIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "startIndex",
+            type: "u64",
+          },
+          {
+            name: "entries",
+            type: {
+              vec: {
+                defined: "MintEntryIx",
+              },
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "MintEntryIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "symbol",
+            type: "string",
+          },
+          {
+            name: "uri",
+            type: "string",
+          },
+          {
+            name: "weight",
+            type: {
+              option: "u16",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SetMintEntryIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "index",
+            type: "u64",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "symbol",
+            type: "string",
+          },
+          {
+            name: "uri",
+            type: "string",
+          },
+          {
+            name: "weight",
+            type: {
+              option: "u16",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SetMintEntryMerkleRootIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "root",
+            type: {
+              array: ["u8", 32],
+            },
+          },
+          {
+            name: "supply",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "SetMintEntryTemplateIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "template",
+            type: {
+              defined: "MintEntryTemplate",
+            },
+          },
+          {
+            name: "supply",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "TruncateSupplyIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "supply",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "InitMintConfigIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "outputMintConfig",
+            type: {
+              defined: "OutputMintConfig",
+            },
+          },
+          {
+            name: "mintPhases",
+            type: {
+              vec: {
+                defined: "MintPhase",
+              },
+            },
+          },
+          {
+            name: "metadata",
+            type: "string",
+          },
+          {
+            name: "maxPerWalletTotal",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "pauseAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "phaseSelection",
+            type: {
+              defined: "MintPhaseSelection",
+            },
+          },
+          {
+            name: "botTaxLamports",
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "SetMintConfigMetadataIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "metadata",
+            type: "string",
+          },
+        ],
+      },
+    },
+    {
+      name: "SetMintConfigProvenanceHashIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "provenanceHash",
+            type: {
+              array: ["u8", 32],
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "UpdateMintConfigIx",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "authority",
+            type: "publicKey",
+          },
+          {
+            name: "outputMintConfig",
+            type: {
+              defined: "OutputMintConfig",
+            },
+          },
+          {
+            name: "mintPhases",
+            type: {
+              vec: {
+                defined: "MintPhase",
+              },
+            },
+          },
+          {
+            name: "metadata",
+            type: "string",
+          },
+          {
+            name: "maxPerWalletTotal",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "pauseAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "phaseSelection",
+            type: {
+              defined: "MintPhaseSelection",
+            },
+          },
+          {
+            name: "botTaxLamports",
+            type: {
+              option: "u64",
+            },
+          },
+        ],
+      },
+    },
+    {
+      name: "OutputMintConfig",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "sellerFeeBasisPoints",
+            type: "u16",
+          },
+          {
+            name: "tokenStandard",
+            type: {
+              defined: "TokenStandard",
+            },
+          },
+          {
+            name: "collection",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "ruleset",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "creators",
+            type: {
+              vec: {
+                defined: "Creator",
+              },
+            },
+          },
+          {
+            name: "merkleTree",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "releaseAuthority",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "randomnessSource",
+            type: {
+              defined: "RandomnessSource",
+            },
+          },
+          {
+            name: "selectionMode",
+            type: {
+              defined: "MintSelectionMode",
+            },
+          },
+          {
+            name: "hiddenSettings",
+            type: {
+              option: {
+                defined: "HiddenSettings",
+              },
+            },
+          },
+        ],
+      },
+    },
     {
-      name: "mintConfig",
+      name: "HiddenSettings",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "bump",
-            type: "u8",
+            name: "name",
+            type: "string",
           },
           {
-            name: "authority",
-            type: "publicKey",
+            name: "uri",
+            type: "string",
           },
           {
-            name: "name",
-            type: "string",
+            name: "revealAuthority",
+            type: {
+              option: "publicKey",
+            },
           },
+        ],
+      },
+    },
+    {
+      name: "VrfRandomness",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "supply",
-            type: "u64",
+            name: "discriminator",
+            type: {
+              array: ["u8", 8],
+            },
           },
           {
-            name: "count",
-            type: "u64",
+            name: "requester",
+            type: "publicKey",
           },
           {
-            name: "outputMintConfig",
+            name: "seed",
             type: {
-              defined: "OutputMintConfig",
+              array: ["u8", 32],
             },
           },
           {
-            name: "mintPhases",
+            name: "fulfilled",
+            type: "bool",
+          },
+          {
+            name: "randomness",
             type: {
-              vec: {
-                defined: "MintPhase",
-              },
+              array: ["u8", 32],
             },
           },
+        ],
+      },
+    },
+    {
+      name: "Creator",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "address",
+            type: "publicKey",
+          },
           {
-            name: "metadata",
-            type: "string",
+            name: "share",
+            type: "u8",
           },
         ],
       },
     },
     {
-      name: "mintPhaseAuthorization",
+      name: "MintPhase",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "bump",
-            type: "u8",
+            name: "startCondition",
+            type: {
+              option: {
+                defined: "MintPhaseCondition",
+              },
+            },
           },
           {
-            name: "mintConfig",
-            type: "publicKey",
+            name: "endCondition",
+            type: {
+              option: {
+                defined: "MintPhaseCondition",
+              },
+            },
           },
           {
-            name: "mintPhaseIndex",
-            type: "u8",
+            name: "tokenChecks",
+            type: {
+              vec: {
+                defined: "MintPhaseTokenCheck",
+              },
+            },
           },
           {
-            name: "user",
-            type: "publicKey",
+            name: "authorization",
+            type: {
+              option: {
+                defined: "MintPhaseAuthorizationCheck",
+              },
+            },
           },
           {
-            name: "count",
-            type: "u64",
+            name: "metadata",
+            type: "string",
           },
           {
-            name: "remaining",
+            name: "priceCurve",
+            type: {
+              option: {
+                defined: "MintPhasePriceCurve",
+              },
+            },
+          },
+          {
+            name: "maxPerWallet",
+            type: {
+              option: "u64",
+            },
+          },
+          {
+            name: "maxSupply",
             type: {
               option: "u64",
             },
           },
+          {
+            name: "minted",
+            type: "u64",
+          },
+          {
+            name: "outputOverrides",
+            type: {
+              option: {
+                defined: "MintPhaseOutputOverrides",
+              },
+            },
+          },
+          {
+            name: "cooldownSeconds",
+            type: {
+              option: "i64",
+            },
+          },
         ],
       },
     },
     {
-      name: "outputMintPendingRelease",
+      name: "MintPhaseOutputOverrides",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "bump",
-            type: "u8",
+            name: "collection",
+            type: {
+              option: "publicKey",
+            },
           },
           {
-            name: "mintConfig",
-            type: "publicKey",
+            name: "creators",
+            type: {
+              option: {
+                vec: {
+                  defined: "Creator",
+                },
+              },
+            },
           },
           {
-            name: "mint",
-            type: "publicKey",
+            name: "sellerFeeBasisPoints",
+            type: {
+              option: "u16",
+            },
           },
           {
-            name: "holder",
-            type: "publicKey",
+            name: "tokenStandard",
+            type: {
+              option: {
+                defined: "TokenStandard",
+              },
+            },
+          },
+          {
+            name: "ruleset",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "releaseAuthority",
+            type: {
+              option: "publicKey",
+            },
           },
         ],
       },
     },
-  ],
-  types: [
     {
-      name: "SetMintPhaseAuthorizationIx",
+      name: "MintPhasePriceTier",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "remaining",
+            name: "upToCount",
+            type: "u64",
+          },
+          {
+            name: "price",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "MintPhaseTokenCheck",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "addressKind",
             type: {
-              option: "u64",
+              defined: "MintPhaseTokenCheckAddressKind",
             },
           },
           {
-            name: "user",
+            name: "address",
             type: "publicKey",
           },
           {
-            name: "mintPhaseIx",
-            type: "u8",
+            name: "amount",
+            type: "u64",
+          },
+          {
+            name: "transferTarget",
+            type: {
+              option: "publicKey",
+            },
+          },
+          {
+            name: "mode",
+            type: {
+              defined: "MintPhaseTokenCheckMode",
+            },
           },
         ],
       },
     },
     {
-      name: "MintIx",
+      name: "MintPhaseAuthorizationCheck",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "mintPhaseIx",
-            type: "u8",
+            name: "mode",
+            type: {
+              defined: "MintPhaseAuthorizationMode",
+            },
           },
         ],
       },
     },
     {
-      name: "SetMintEntryIx",
+      name: "MintEntry",
       type: {
         kind: "struct",
         fields: [
-          {
-            name: "index",
-            type: "u64",
-          },
           {
             name: "name",
             type: "string",
@@ -1477,75 +4963,109 @@ export const IDL: MintGenerator = {
             name: "uri",
             type: "string",
           },
+          {
+            name: "weight",
+            type: "u16",
+          },
         ],
       },
     },
     {
-      name: "InitMintConfigIx",
+      name: "MerkleMintEntry",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "authority",
-            type: "publicKey",
+            name: "index",
+            type: "u64",
           },
           {
             name: "name",
             type: "string",
           },
           {
-            name: "outputMintConfig",
-            type: {
-              defined: "OutputMintConfig",
-            },
+            name: "symbol",
+            type: "string",
           },
           {
-            name: "mintPhases",
+            name: "uri",
+            type: "string",
+          },
+          {
+            name: "proof",
             type: {
               vec: {
-                defined: "MintPhase",
+                array: ["u8", 32],
               },
             },
           },
-          {
-            name: "metadata",
-            type: "string",
-          },
         ],
       },
     },
     {
-      name: "SetMintConfigMetadataIx",
+      name: "MintEntryTemplate",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "metadata",
+            name: "namePrefix",
+            type: "string",
+          },
+          {
+            name: "symbol",
+            type: "string",
+          },
+          {
+            name: "uriPrefix",
+            type: "string",
+          },
+          {
+            name: "uriSuffix",
             type: "string",
           },
+          {
+            name: "indexOffset",
+            type: "u64",
+          },
         ],
       },
     },
     {
-      name: "UpdateMintConfigIx",
+      name: "MintConfigV0",
       type: {
         kind: "struct",
         fields: [
+          {
+            name: "bump",
+            type: "u8",
+          },
           {
             name: "authority",
             type: "publicKey",
           },
+          {
+            name: "name",
+            type: "string",
+          },
+          {
+            name: "supply",
+            type: "u64",
+          },
+          {
+            name: "count",
+            type: "u64",
+          },
           {
             name: "outputMintConfig",
             type: {
-              defined: "OutputMintConfig",
+              defined: "OutputMintConfigV0",
             },
           },
           {
             name: "mintPhases",
             type: {
               vec: {
-                defined: "MintPhase",
+                defined: "MintPhaseV0",
               },
             },
           },
@@ -1557,7 +5077,7 @@ export const IDL: MintGenerator = {
       },
     },
     {
-      name: "OutputMintConfig",
+      name: "OutputMintConfigV0",
       type: {
         kind: "struct",
         fields: [
@@ -1607,23 +5127,7 @@ export const IDL: MintGenerator = {
       },
     },
     {
-      name: "Creator",
-      type: {
-        kind: "struct",
-        fields: [
-          {
-            name: "address",
-            type: "publicKey",
-          },
-          {
-            name: "share",
-            type: "u8",
-          },
-        ],
-      },
-    },
-    {
-      name: "MintPhase",
+      name: "MintPhaseV0",
       type: {
         kind: "struct",
         fields: [
@@ -1631,7 +5135,7 @@ export const IDL: MintGenerator = {
             name: "startCondition",
             type: {
               option: {
-                defined: "MintPhaseStartEndCondition",
+                defined: "MintPhaseStartEndConditionV0",
               },
             },
           },
@@ -1639,7 +5143,7 @@ export const IDL: MintGenerator = {
             name: "endCondition",
             type: {
               option: {
-                defined: "MintPhaseStartEndCondition",
+                defined: "MintPhaseStartEndConditionV0",
               },
             },
           },
@@ -1667,7 +5171,7 @@ export const IDL: MintGenerator = {
       },
     },
     {
-      name: "MintPhaseStartEndCondition",
+      name: "MintPhaseStartEndConditionV0",
       type: {
         kind: "struct",
         fields: [
@@ -1687,69 +5191,201 @@ export const IDL: MintGenerator = {
       },
     },
     {
-      name: "MintPhaseTokenCheck",
+      name: "GetActivePhasesIx",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "addressKind",
-            type: {
-              defined: "MintPhaseTokenCheckAddressKind",
-            },
-          },
-          {
-            name: "address",
+            name: "user",
             type: "publicKey",
           },
+        ],
+      },
+    },
+    {
+      name: "ActivePhase",
+      type: {
+        kind: "struct",
+        fields: [
           {
-            name: "amount",
-            type: "u64",
-          },
-          {
-            name: "transferTarget",
-            type: {
-              option: "publicKey",
-            },
+            name: "mintPhaseIndex",
+            type: "u8",
           },
           {
-            name: "mode",
+            name: "remaining",
             type: {
-              defined: "MintPhaseTokenCheckMode",
+              option: "u64",
             },
           },
         ],
       },
     },
     {
-      name: "MintPhaseAuthorizationCheck",
+      name: "GetMintEntryIx",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "mode",
-            type: {
-              defined: "MintPhaseAuthorizationMode",
-            },
+            name: "index",
+            type: "u64",
           },
         ],
       },
     },
     {
-      name: "MintEntry",
+      name: "RemainingSupply",
       type: {
         kind: "struct",
         fields: [
           {
-            name: "name",
-            type: "string",
+            name: "supply",
+            type: "u64",
           },
           {
-            name: "symbol",
-            type: "string",
+            name: "count",
+            type: "u64",
           },
           {
-            name: "uri",
-            type: "string",
+            name: "pendingReveals",
+            type: "u64",
+          },
+          {
+            name: "available",
+            type: "u64",
+          },
+        ],
+      },
+    },
+    {
+      name: "MintPhaseCondition",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "All",
+            fields: [
+              {
+                name: "conditions",
+                type: {
+                  vec: {
+                    defined: "MintPhaseCondition",
+                  },
+                },
+              },
+            ],
+          },
+          {
+            name: "Any",
+            fields: [
+              {
+                name: "conditions",
+                type: {
+                  vec: {
+                    defined: "MintPhaseCondition",
+                  },
+                },
+              },
+            ],
+          },
+          {
+            name: "Not",
+            fields: [
+              {
+                name: "conditions",
+                type: {
+                  vec: {
+                    defined: "MintPhaseCondition",
+                  },
+                },
+              },
+            ],
+          },
+          {
+            name: "Time",
+            fields: [
+              {
+                name: "seconds",
+                type: "i64",
+              },
+            ],
+          },
+          {
+            name: "Count",
+            fields: [
+              {
+                name: "count",
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "Slot",
+            fields: [
+              {
+                name: "slot",
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "PhaseSoldOut",
+            fields: [
+              {
+                name: "mintPhaseIndex",
+                type: "u8",
+              },
+            ],
+          },
+        ],
+      },
+    },
+    {
+      name: "MintPhaseSelection",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "First",
+          },
+          {
+            name: "Cheapest",
+          },
+        ],
+      },
+    },
+    {
+      name: "MintSelectionMode",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Random",
+          },
+          {
+            name: "Sequential",
+          },
+        ],
+      },
+    },
+    {
+      name: "RandomnessSource",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "SlotHashes",
+          },
+          {
+            name: "CommitReveal",
+          },
+          {
+            name: "Vrf",
+            fields: [
+              {
+                name: "oracleProgram",
+                type: "publicKey",
+              },
+            ],
           },
         ],
       },
@@ -1777,6 +5413,65 @@ export const IDL: MintGenerator = {
         ],
       },
     },
+    {
+      name: "MintPhasePriceCurve",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "DutchAuction",
+            fields: [
+              {
+                name: "startSeconds",
+                type: "i64",
+              },
+              {
+                name: "startPrice",
+                type: "u64",
+              },
+              {
+                name: "floorPrice",
+                type: "u64",
+              },
+              {
+                name: "decayPerInterval",
+                type: "u64",
+              },
+              {
+                name: "intervalSeconds",
+                type: "i64",
+              },
+            ],
+          },
+          {
+            name: "Linear",
+            fields: [
+              {
+                name: "startPrice",
+                type: "u64",
+              },
+              {
+                name: "incrementPerMint",
+                type: "u64",
+              },
+            ],
+          },
+          {
+            name: "Tiers",
+            fields: [
+              {
+                name: "tiers",
+                type: {
+                  vec: {
+                    defined: "MintPhasePriceTier",
+                  },
+                },
+              },
+            ],
+          },
+        ],
+      },
+    },
     {
       name: "MintPhaseTokenCheckAddressKind",
       type: {
@@ -1825,6 +5520,50 @@ export const IDL: MintGenerator = {
         ],
       },
     },
+    {
+      name: "MintEntryStorage",
+      type: {
+        kind: "enum",
+        variants: [
+          {
+            name: "Inline",
+          },
+          {
+            name: "Template",
+            fields: [
+              {
+                name: "template",
+                type: {
+                  defined: "MintEntryTemplate",
+                },
+              },
+            ],
+          },
+          {
+            name: "Paged",
+            fields: [
+              {
+                name: "pageRemaining",
+                type: {
+                  vec: "u32",
+                },
+              },
+            ],
+          },
+          {
+            name: "Merkle",
+            fields: [
+              {
+                name: "root",
+                type: {
+                  array: ["u8", 32],
+                },
+              },
+            ],
+          },
+        ],
+      },
+    },
   ],
   errors: [
     {
@@ -1847,6 +5586,36 @@ export const IDL: MintGenerator = {
       name: "InvalidProgramId",
       msg: "Invalid program id",
     },
+    {
+      code: 6004,
+      name: "InvalidMintEntryWeight",
+      msg: "Invalid mint entry weight",
+    },
+    {
+      code: 6005,
+      name: "MintEntryFieldTooLong",
+      msg: "Mint entry field too long",
+    },
+    {
+      code: 6006,
+      name: "InvalidMintEntryData",
+      msg: "Invalid mint entry data",
+    },
+    {
+      code: 6007,
+      name: "InvalidPendingMint",
+      msg: "Invalid pending mint",
+    },
+    {
+      code: 6008,
+      name: "InvalidHiddenMint",
+      msg: "Invalid hidden mint",
+    },
+    {
+      code: 6009,
+      name: "InvalidPriceCurve",
+      msg: "Invalid price curve",
+    },
     {
       code: 6010,
       name: "TooManyCreators",
@@ -1867,6 +5636,36 @@ export const IDL: MintGenerator = {
       name: "ProgrammableAndMerkleTree",
       msg: "Cannot mint with programmably nft and merkle tree",
     },
+    {
+      code: 6014,
+      name: "SequentialAndRandomnessSource",
+      msg: "Cannot use sequential selection with a deferred randomness source",
+    },
+    {
+      code: 6015,
+      name: "ProvenanceHashSet",
+      msg: "Mint entries are locked by the provenance hash",
+    },
+    {
+      code: 6016,
+      name: "ProvenanceHashNotSet",
+      msg: "Provenance hash not set",
+    },
+    {
+      code: 6017,
+      name: "ProvenanceHashMismatch",
+      msg: "Provenance hash does not match mint entries",
+    },
+    {
+      code: 6018,
+      name: "HiddenAndMerkleTree",
+      msg: "Cannot mint hidden tokens into a merkle tree",
+    },
+    {
+      code: 6019,
+      name: "InvalidHiddenSettings",
+      msg: "Invalid hidden settings",
+    },
     {
       code: 6020,
       name: "MintingAlreadyStarted",
@@ -1887,6 +5686,36 @@ export const IDL: MintGenerator = {
       name: "NotTokensRemaining",
       msg: "No tokens remaining",
     },
+    {
+      code: 6024,
+      name: "RevealTooEarly",
+      msg: "Mint must be revealed in a later slot than it was committed",
+    },
+    {
+      code: 6025,
+      name: "InvalidVrfRandomness",
+      msg: "Invalid vrf randomness account",
+    },
+    {
+      code: 6026,
+      name: "VrfRandomnessNotFulfilled",
+      msg: "Vrf randomness not fulfilled",
+    },
+    {
+      code: 6027,
+      name: "InvalidMerkleMintEntry",
+      msg: "Merkle mint entry does not match the picked index",
+    },
+    {
+      code: 6028,
+      name: "InvalidMerkleProof",
+      msg: "Invalid merkle proof",
+    },
+    {
+      code: 6029,
+      name: "MintLimitReached",
+      msg: "Mint limit per wallet reached",
+    },
     {
       code: 6030,
       name: "HolderNotSigner",
@@ -1927,6 +5756,21 @@ export const IDL: MintGenerator = {
       name: "IncorrectAuthorizationHolder",
       msg: "IncorrectAuthorizationHolder",
     },
+    {
+      code: 6042,
+      name: "InvalidMintCounter",
+      msg: "Invalid mint counter",
+    },
+    {
+      code: 6043,
+      name: "InvalidMintCooldown",
+      msg: "Invalid mint cooldown",
+    },
+    {
+      code: 6044,
+      name: "UserNotSigner",
+      msg: "User must be signer to mint with per wallet limits or a deferred reveal",
+    },
     {
       code: 6050,
       name: "ReleaseTimeInvalid",
@@ -1937,5 +5781,85 @@ export const IDL: MintGenerator = {
       name: "InvalidOutputMintsPendingRelease",
       msg: "Invalid output mints pending release",
     },
+    {
+      code: 6060,
+      name: "InvalidMintEntryStorage",
+      msg: "Invalid mint entry storage",
+    },
+    {
+      code: 6061,
+      name: "InvalidMintEntryTemplate",
+      msg: "Invalid mint entry template",
+    },
+    {
+      code: 6062,
+      name: "InvalidMintEntryPage",
+      msg: "Invalid mint entry page",
+    },
+    {
+      code: 6063,
+      name: "MerkleAndRandomnessSource",
+      msg: "Merkle entries picked at random need a deferred randomness source",
+    },
+    {
+      code: 6064,
+      name: "PagedAndRandomnessSource",
+      msg: "Paged entries picked at random need a deferred randomness source",
+    },
+    {
+      code: 6070,
+      name: "InvalidPhaseCondition",
+      msg: "Invalid phase condition",
+    },
+    {
+      code: 6071,
+      name: "PhaseEnded",
+      msg: "Phase ended",
+    },
+    {
+      code: 6072,
+      name: "PhaseSoldOut",
+      msg: "Phase sold out",
+    },
+    {
+      code: 6073,
+      name: "NoActivePhase",
+      msg: "No active phase",
+    },
+    {
+      code: 6074,
+      name: "PhaseNotStarted",
+      msg: "Phase not started",
+    },
+    {
+      code: 6080,
+      name: "MintPaused",
+      msg: "Minting is paused",
+    },
+    {
+      code: 6081,
+      name: "MintCooldownActive",
+      msg: "Mint cooldown has not passed",
+    },
+    {
+      code: 6090,
+      name: "PendingMintExpired",
+      msg: "Pending mint expired and can only be released with expire_pending_mint",
+    },
+    {
+      code: 6091,
+      name: "PendingMintNotExpired",
+      msg: "Pending mint has not expired",
+    },
+    {
+      code: 6092,
+      name: "PendingMintAlreadyFulfilled",
+      msg: "Pending mint randomness already fulfilled",
+    },
+    {
+      code: 6100,
+      name: "InvalidLegacyMintConfig",
+      msg: "Mint config is not a legacy mint config",
+    },
   ],
 };
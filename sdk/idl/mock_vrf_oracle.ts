@@ -0,0 +1,245 @@
+export type MockVrfOracle = {
+  version: "0.1.0";
+  name: "mock_vrf_oracle";
+  instructions: [
+    {
+      name: "requestRandomness";
+      accounts: [
+        {
+          name: "vrfRandomness";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "requester";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: true;
+        },
+        {
+          name: "systemProgram";
+          isMut: false;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "seed";
+          type: {
+            array: ["u8", 32];
+          };
+        }
+      ];
+    },
+    {
+      name: "fulfillRandomness";
+      accounts: [
+        {
+          name: "vrfRandomness";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "requester";
+          isMut: true;
+          isSigner: false;
+        },
+        {
+          name: "callbackProgram";
+          isMut: false;
+          isSigner: false;
+        },
+        {
+          name: "authority";
+          isMut: false;
+          isSigner: true;
+        },
+        {
+          name: "payer";
+          isMut: true;
+          isSigner: false;
+        }
+      ];
+      args: [
+        {
+          name: "randomness";
+          type: {
+            array: ["u8", 32];
+          };
+        }
+      ];
+    }
+  ];
+  accounts: [
+    {
+      name: "vrfRandomness";
+      type: {
+        kind: "struct";
+        fields: [
+          {
+            name: "requester";
+            type: "publicKey";
+          },
+          {
+            name: "seed";
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "fulfilled";
+            type: "bool";
+          },
+          {
+            name: "randomness";
+            type: {
+              array: ["u8", 32];
+            };
+          },
+          {
+            name: "bump";
+            type: "u8";
+          },
+          {
+            name: "payer";
+            type: "publicKey";
+          }
+        ];
+      };
+    }
+  ];
+  errors: [
+    {
+      code: 6000;
+      name: "AlreadyFulfilled";
+      msg: "Randomness already fulfilled";
+    }
+  ];
+};
+
+export const IDL: MockVrfOracle = {
+  version: "0.1.0",
+  name: "mock_vrf_oracle",
+  instructions: [
+    {
+      name: "requestRandomness",
+      accounts: [
+        {
+          name: "vrfRandomness",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "requester",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: true,
+        },
+        {
+          name: "systemProgram",
+          isMut: false,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "seed",
+          type: {
+            array: ["u8", 32],
+          },
+        },
+      ],
+    },
+    {
+      name: "fulfillRandomness",
+      accounts: [
+        {
+          name: "vrfRandomness",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "requester",
+          isMut: true,
+          isSigner: false,
+        },
+        {
+          name: "callbackProgram",
+          isMut: false,
+          isSigner: false,
+        },
+        {
+          name: "authority",
+          isMut: false,
+          isSigner: true,
+        },
+        {
+          name: "payer",
+          isMut: true,
+          isSigner: false,
+        },
+      ],
+      args: [
+        {
+          name: "randomness",
+          type: {
+            array: ["u8", 32],
+          },
+        },
+      ],
+    },
+  ],
+  accounts: [
+    {
+      name: "vrfRandomness",
+      type: {
+        kind: "struct",
+        fields: [
+          {
+            name: "requester",
+            type: "publicKey",
+          },
+          {
+            name: "seed",
+            type: {
+              array: ["u8", 32],
+            },
+          },
+          {
+            name: "fulfilled",
+            type: "bool",
+          },
+          {
+            name: "randomness",
+            type: {
+              array: ["u8", 32],
+            },
+          },
+          {
+            name: "bump",
+            type: "u8",
+          },
+          {
+            name: "payer",
+            type: "publicKey",
+          },
+        ],
+      },
+    },
+  ],
+  errors: [
+    {
+      code: 6000,
+      name: "AlreadyFulfilled",
+      msg: "Randomness already fulfilled",
+    },
+  ],
+};
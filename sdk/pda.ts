@@ -53,6 +53,106 @@ export const findOutputMintPendingReleaseId = (
   )[0];
 };
 
+export const MINT_COUNTER_PREFIX = "mint-counter";
+export const findMintCounterId = (
+  mintConfigId: PublicKey,
+  mintPhaseIx: number,
+  user: PublicKey,
+  programId = MINT_GENERATOR_PROGRAM_ID
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(MINT_COUNTER_PREFIX),
+      mintConfigId.toBuffer(),
+      new BN(mintPhaseIx).toArrayLike(Buffer, "le", 1),
+      user.toBuffer(),
+    ],
+    programId
+  )[0];
+};
+
+export const MINT_COOLDOWN_PREFIX = "mint-cooldown";
+export const findMintCooldownId = (
+  mintConfigId: PublicKey,
+  mintPhaseIx: number,
+  user: PublicKey,
+  programId = MINT_GENERATOR_PROGRAM_ID
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(MINT_COOLDOWN_PREFIX),
+      mintConfigId.toBuffer(),
+      new BN(mintPhaseIx).toArrayLike(Buffer, "le", 1),
+      user.toBuffer(),
+    ],
+    programId
+  )[0];
+};
+
+export const MINT_WALLET_COUNTER_PREFIX = "mint-wallet-counter";
+export const findMintWalletCounterId = (
+  mintConfigId: PublicKey,
+  user: PublicKey,
+  programId = MINT_GENERATOR_PROGRAM_ID
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(MINT_WALLET_COUNTER_PREFIX),
+      mintConfigId.toBuffer(),
+      user.toBuffer(),
+    ],
+    programId
+  )[0];
+};
+
+export const PENDING_MINT_PREFIX = "pending-mint";
+export const findPendingMintId = (
+  mintConfigId: PublicKey,
+  user: PublicKey,
+  programId = MINT_GENERATOR_PROGRAM_ID
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(PENDING_MINT_PREFIX),
+      mintConfigId.toBuffer(),
+      user.toBuffer(),
+    ],
+    programId
+  )[0];
+};
+
+export const MINT_ENTRY_PAGE_PREFIX = "mint-entry-page";
+export const findMintEntryPageId = (
+  mintConfigId: PublicKey,
+  pageIndex: number,
+  programId = MINT_GENERATOR_PROGRAM_ID
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(MINT_ENTRY_PAGE_PREFIX),
+      mintConfigId.toBuffer(),
+      new BN(pageIndex).toArrayLike(Buffer, "le", 4),
+    ],
+    programId
+  )[0];
+};
+
+export const HIDDEN_MINT_PREFIX = "hidden-mint";
+export const findHiddenMintId = (
+  mintConfigId: PublicKey,
+  mintId: PublicKey,
+  programId = MINT_GENERATOR_PROGRAM_ID
+): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [
+      utils.bytes.utf8.encode(HIDDEN_MINT_PREFIX),
+      mintConfigId.toBuffer(),
+      mintId.toBuffer(),
+    ],
+    programId
+  )[0];
+};
+
 export const findCollectionDelegateId = (
   collectionMintId: PublicKey,
   updateAuthority: PublicKey,
@@ -21,12 +21,18 @@ import {
 import type { AccountMeta, Connection } from "@solana/web3.js";
 import { PublicKey, SYSVAR_INSTRUCTIONS_PUBKEY } from "@solana/web3.js";
 
-import type { IdlTypes } from "./constants";
+import type { IdlAccountData, IdlTypes } from "./constants";
 import {
   findCollectionDelegateId,
+  findHiddenMintId,
   findMerkleTreeAuthorityId,
+  findMintCooldownId,
+  findMintCounterId,
+  findMintEntryPageId,
   findMintPhaseAuthorizationId,
+  findMintWalletCounterId,
   findOutputMintPendingReleaseId,
+  findPendingMintId,
 } from "./pda";
 
 export const getRemainingAccountsForTokenChecks = async (
@@ -376,3 +382,123 @@ export const remainingAccountsForMintCnft = (
     },
   ];
 };
+
+export const phaseOutputMintConfig = (
+  mintConfig: IdlAccountData<"mintConfig">["parsed"],
+  mintPhaseIx: number
+): IdlTypes["OutputMintConfig"] => {
+  const outputOverrides = mintConfig.mintPhases[mintPhaseIx]?.outputOverrides;
+  const outputMintConfig = mintConfig.outputMintConfig;
+  if (!outputOverrides) return outputMintConfig;
+  return {
+    ...outputMintConfig,
+    collection: outputOverrides.collection ?? outputMintConfig.collection,
+    creators: outputOverrides.creators ?? outputMintConfig.creators,
+    sellerFeeBasisPoints:
+      outputOverrides.sellerFeeBasisPoints ??
+      outputMintConfig.sellerFeeBasisPoints,
+    tokenStandard:
+      outputOverrides.tokenStandard ?? outputMintConfig.tokenStandard,
+    ruleset: outputOverrides.ruleset ?? outputMintConfig.ruleset,
+    releaseAuthority:
+      outputOverrides.releaseAuthority ?? outputMintConfig.releaseAuthority,
+  };
+};
+
+export const remainingAccountsForMintGuards = (
+  mintConfigId: PublicKey,
+  mintPhaseIx: number,
+  user: PublicKey,
+  mintPhase: IdlTypes["MintPhase"],
+  maxPerWalletTotal: IdlAccountData<"mintConfig">["parsed"]["maxPerWalletTotal"]
+): AccountMeta[] => {
+  const remainingAccounts: AccountMeta[] = [];
+  if (mintPhase.cooldownSeconds) {
+    remainingAccounts.push({
+      pubkey: findMintCooldownId(mintConfigId, mintPhaseIx, user),
+      isWritable: true,
+      isSigner: false,
+    });
+  }
+  if (mintPhase.maxPerWallet) {
+    remainingAccounts.push({
+      pubkey: findMintCounterId(mintConfigId, mintPhaseIx, user),
+      isWritable: true,
+      isSigner: false,
+    });
+  }
+  if (maxPerWalletTotal) {
+    remainingAccounts.push({
+      pubkey: findMintWalletCounterId(mintConfigId, user),
+      isWritable: true,
+      isSigner: false,
+    });
+  }
+  return remainingAccounts;
+};
+
+export const remainingAccountsForPendingMint = (
+  mintConfigId: PublicKey,
+  user: PublicKey,
+  randomnessSource: IdlTypes["RandomnessSource"],
+  vrfRandomnessId?: PublicKey
+): AccountMeta[] => {
+  const remainingAccounts: AccountMeta[] = [
+    {
+      pubkey: findPendingMintId(mintConfigId, user),
+      isWritable: true,
+      isSigner: false,
+    },
+  ];
+  if (randomnessSource.vrf) {
+    if (!vrfRandomnessId) throw "Vrf randomness account required";
+    remainingAccounts.push(
+      {
+        pubkey: vrfRandomnessId,
+        isWritable: true,
+        isSigner: false,
+      },
+      {
+        pubkey: randomnessSource.vrf.oracleProgram,
+        isWritable: false,
+        isSigner: false,
+      }
+    );
+  }
+  return remainingAccounts;
+};
+
+export const remainingAccountsForMintEntryPage = (
+  mintConfigId: PublicKey,
+  entryStorage: IdlTypes["MintEntryStorage"],
+  pageIndex?: number
+): AccountMeta[] => {
+  if (!entryStorage.paged) return [];
+  // entries are picked in order so the first page with entries left is used
+  const index =
+    pageIndex ??
+    entryStorage.paged.pageRemaining.findIndex((remaining) => remaining > 0);
+  if (index < 0) throw "No mint entry pages remaining";
+  return [
+    {
+      pubkey: findMintEntryPageId(mintConfigId, index),
+      isWritable: true,
+      isSigner: false,
+    },
+  ];
+};
+
+export const remainingAccountsForHiddenMint = (
+  mintConfigId: PublicKey,
+  mintId: PublicKey,
+  hiddenSettings: IdlTypes["HiddenSettings"] | null
+): AccountMeta[] => {
+  if (!hiddenSettings) return [];
+  return [
+    {
+      pubkey: findHiddenMintId(mintConfigId, mintId),
+      isWritable: true,
+      isSigner: false,
+    },
+  ];
+};
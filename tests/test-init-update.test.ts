@@ -3,6 +3,7 @@ import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
 import { beforeAll, test } from "@jest/globals";
 import type { Connection } from "@solana/web3.js";
 import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import { BN } from "bn.js";
 
 import {
   fetchIdlAccount,
@@ -35,9 +36,16 @@ test("Init mint config", async () => {
           creators: [],
           merkleTree: null,
           releaseAuthority: null,
+          randomnessSource: { slotHashes: undefined },
+          selectionMode: { random: undefined },
+          hiddenSettings: null,
         },
         mintPhases: [],
         metadata: "",
+        maxPerWalletTotal: null,
+        pauseAuthority: null,
+        phaseSelection: { first: undefined },
+        botTaxLamports: null,
       })
       .accountsStrict({
         mintConfig: findMintConfigId(configName),
@@ -80,6 +88,9 @@ test("Update mint config", async () => {
           creators: [],
           merkleTree: null,
           releaseAuthority: null,
+          randomnessSource: { slotHashes: undefined },
+          selectionMode: { random: undefined },
+          hiddenSettings: null,
         },
         mintPhases: [
           {
@@ -88,9 +99,19 @@ test("Update mint config", async () => {
             endCondition: null,
             tokenChecks: [],
             authorization: null,
+            priceCurve: null,
+            maxPerWallet: null,
+            maxSupply: null,
+            minted: new BN(0),
+            outputOverrides: null,
+            cooldownSeconds: null,
           },
         ],
         metadata: `{"test":"value"}`,
+        maxPerWalletTotal: null,
+        pauseAuthority: null,
+        phaseSelection: { first: undefined },
+        botTaxLamports: null,
       })
       .accountsStrict({
         mintConfig: findMintConfigId(configName),
@@ -36,6 +36,9 @@ test("Init mint config", async () => {
           creators: [{ address: wallet.publicKey, share: 100 }],
           merkleTree: null,
           releaseAuthority: null,
+          randomnessSource: { slotHashes: undefined },
+          selectionMode: { random: undefined },
+          hiddenSettings: null,
         },
         mintPhases: [
           {
@@ -44,9 +47,19 @@ test("Init mint config", async () => {
             endCondition: null,
             tokenChecks: [],
             authorization: null,
+            priceCurve: null,
+            maxPerWallet: null,
+            maxSupply: null,
+            minted: new BN(0),
+            outputOverrides: null,
+            cooldownSeconds: null,
           },
         ],
         metadata: "",
+        maxPerWalletTotal: null,
+        pauseAuthority: null,
+        phaseSelection: { first: undefined },
+        botTaxLamports: null,
       })
       .accountsStrict({
         mintConfig: findMintConfigId(configName),
@@ -83,6 +96,7 @@ test("Add entry", async () => {
           name: `name${i}`,
           symbol: `sym${i}`,
           uri: `uri${i}`,
+          weight: null,
         })
         .accountsStrict({
           mintConfig: findMintConfigId(configName),
@@ -15,6 +15,8 @@ import {
   findMintConfigId,
   mint,
   MINT_ENTRY_SIZE,
+  MINT_ENTRY_WEIGHT_BLOCK_SIZE,
+  MINT_ENTRY_WEIGHT_BLOCK_SUM_LENGTH,
   mintGeneratorProgram,
 } from "../sdk";
 import { getTestConfigName, getTestProvider } from "./utils";
@@ -43,6 +45,9 @@ test("Init mint config", async () => {
           creators: [{ address: wallet.publicKey, share: 100 }],
           merkleTree: null,
           releaseAuthority: null,
+          randomnessSource: { slotHashes: undefined },
+          selectionMode: { random: undefined },
+          hiddenSettings: null,
         },
         mintPhases: [
           {
@@ -51,9 +56,19 @@ test("Init mint config", async () => {
             endCondition: null,
             tokenChecks: [],
             authorization: null,
+            priceCurve: null,
+            maxPerWallet: null,
+            maxSupply: null,
+            minted: new BN(0),
+            outputOverrides: null,
+            cooldownSeconds: null,
           },
         ],
         metadata: "",
+        maxPerWalletTotal: null,
+        pauseAuthority: null,
+        phaseSelection: { first: undefined },
+        botTaxLamports: null,
       })
       .accountsStrict({
         mintConfig: findMintConfigId(configName),
@@ -92,6 +107,7 @@ test("Add entry", async () => {
           name: `name${i}`,
           symbol: `sym${i}`,
           uri: `uri${i}`,
+          weight: null,
         })
         .accountsStrict({
           mintConfig: findMintConfigId(configName),
@@ -108,9 +124,13 @@ test("Add entry", async () => {
       "mintConfig"
     );
 
-    // check resize
+    // check resize - the header gets a weight block sum every MINT_ENTRY_WEIGHT_BLOCK_SIZE entries
     expect(mintConfig.data.length).toEqual(
-      mintConfigAccountBefore!.data.length + MINT_ENTRY_SIZE
+      mintConfigAccountBefore!.data.length +
+        MINT_ENTRY_SIZE +
+        (i % MINT_ENTRY_WEIGHT_BLOCK_SIZE === 0
+          ? MINT_ENTRY_WEIGHT_BLOCK_SUM_LENGTH
+          : 0)
     );
 
     // check data
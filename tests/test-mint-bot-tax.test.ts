@@ -0,0 +1,244 @@
+import { executeTransaction } from "@cardinal/common";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import { beforeAll, test } from "@jest/globals";
+import type { Connection } from "@solana/web3.js";
+import {
+  SystemProgram,
+  SYSVAR_SLOT_HASHES_PUBKEY,
+  Transaction,
+} from "@solana/web3.js";
+import { BN } from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findMintConfigId,
+  mint,
+  mintGeneratorProgram,
+} from "../sdk";
+import { getTestConfigName, getTestProvider } from "./utils";
+
+let connection: Connection;
+let wallet: Wallet;
+let minter: Wallet;
+const configName = getTestConfigName();
+const botTaxLamports = 10_000_000;
+beforeAll(async () => {
+  const provider = await getTestProvider();
+  connection = provider.connection;
+  wallet = provider.wallet;
+  // the authority is exempt from phase checks so a separate wallet mints
+  minter = (await getTestProvider()).wallet;
+});
+
+test("Init mint config", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.initMintConfig({
+        authority: wallet.publicKey,
+        name: configName,
+        outputMintConfig: {
+          sellerFeeBasisPoints: 10,
+          tokenStandard: { nonFungible: undefined },
+          collection: null,
+          ruleset: null,
+          creators: [{ address: wallet.publicKey, share: 100 }],
+          merkleTree: null,
+          releaseAuthority: null,
+          randomnessSource: { slotHashes: undefined },
+          selectionMode: { random: undefined },
+          hiddenSettings: null,
+        },
+        mintPhases: [
+          {
+            metadata: `{}`,
+            startCondition: {
+              time: {
+                seconds: new BN(Math.floor(Date.now() / 1000) + 60 * 60),
+              },
+            },
+            endCondition: null,
+            tokenChecks: [],
+            authorization: null,
+            priceCurve: null,
+            maxPerWallet: null,
+            maxSupply: null,
+            minted: new BN(0),
+            outputOverrides: null,
+            cooldownSeconds: null,
+          },
+        ],
+        metadata: "",
+        maxPerWalletTotal: null,
+        pauseAuthority: null,
+        phaseSelection: { first: undefined },
+        botTaxLamports: new BN(botTaxLamports),
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(mintConfig.parsed.name).toEqual(configName);
+  expect(Number(mintConfig.parsed.botTaxLamports)).toEqual(botTaxLamports);
+});
+
+test("Add entry", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.setMintEntry({
+        index: new BN(0),
+        name: `name0`,
+        symbol: `sym0`,
+        uri: `uri0`,
+        weight: null,
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(Number(mintConfig.parsed.supply)).toEqual(1);
+});
+
+test("Mint before phase start charges bot tax", async () => {
+  const mintConfigAccountBefore = await connection.getAccountInfo(
+    findMintConfigId(configName)
+  );
+  const authorityBalanceBefore = await connection.getBalance(
+    wallet.publicKey
+  );
+  const minterBalanceBefore = await connection.getBalance(minter.publicKey);
+  const [tx, outputMintKeypair] = await mint(
+    connection,
+    minter,
+    findMintConfigId(configName),
+    0
+  );
+  if (!outputMintKeypair) throw "No output mint keypair";
+  await executeTransaction(connection, tx, minter, {
+    signers: [outputMintKeypair],
+  });
+
+  // check tax is collected by the authority
+  const authorityBalanceAfter = await connection.getBalance(wallet.publicKey);
+  expect(authorityBalanceAfter - authorityBalanceBefore).toEqual(
+    botTaxLamports
+  );
+  const minterBalanceAfter = await connection.getBalance(minter.publicKey);
+  expect(minterBalanceBefore - minterBalanceAfter).toBeGreaterThanOrEqual(
+    botTaxLamports
+  );
+
+  // check mint config is unchanged
+  const mintConfigAccountAfter = await connection.getAccountInfo(
+    findMintConfigId(configName)
+  );
+  expect(mintConfigAccountAfter?.data).toEqual(mintConfigAccountBefore?.data);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(Number(mintConfig.parsed.supply)).toEqual(1);
+  expect(Number(mintConfig.parsed.count)).toEqual(0);
+  expect(Number(mintConfig.parsed.mintPhases[0]!.minted)).toEqual(0);
+
+  // check nothing is minted
+  const outputMintInfo = await connection.getAccountInfo(
+    outputMintKeypair.publicKey
+  );
+  expect(outputMintInfo).toBeNull();
+});
+
+test("Mint auto before phase start charges bot tax", async () => {
+  const mintConfigAccountBefore = await connection.getAccountInfo(
+    findMintConfigId(configName)
+  );
+  const authorityBalanceBefore = await connection.getBalance(
+    wallet.publicKey
+  );
+  // phase 0 has no checks or guards so its account group is empty
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, minter)
+      .methods.mintAuto({
+        merkleMintEntry: null,
+        phaseAccounts: [{ mintPhaseIndex: 0, accountsLength: 0 }],
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        user: minter.publicKey,
+        payer: minter.publicKey,
+        collector: wallet.publicKey,
+        recentSlothashes: SYSVAR_SLOT_HASHES_PUBKEY,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, minter);
+
+  // check tax is collected by the authority
+  const authorityBalanceAfter = await connection.getBalance(wallet.publicKey);
+  expect(authorityBalanceAfter - authorityBalanceBefore).toEqual(
+    botTaxLamports
+  );
+
+  // check mint config is unchanged
+  const mintConfigAccountAfter = await connection.getAccountInfo(
+    findMintConfigId(configName)
+  );
+  expect(mintConfigAccountAfter?.data).toEqual(mintConfigAccountBefore?.data);
+});
+
+test("Mint while paused fails without bot tax", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.pauseMint()
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, wallet);
+
+  const authorityBalanceBefore = await connection.getBalance(
+    wallet.publicKey
+  );
+  const [mintTx, outputMintKeypair] = await mint(
+    connection,
+    minter,
+    findMintConfigId(configName),
+    0
+  );
+  if (!outputMintKeypair) throw "No output mint keypair";
+  await expect(
+    executeTransaction(connection, mintTx, minter, {
+      signers: [outputMintKeypair],
+      silent: true,
+    })
+  ).rejects.toThrow();
+  const authorityBalanceAfter = await connection.getBalance(wallet.publicKey);
+  expect(authorityBalanceAfter).toEqual(authorityBalanceBefore);
+});
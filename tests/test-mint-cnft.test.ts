@@ -92,6 +92,9 @@ test("Init mint config", async () => {
           creators: [{ address: wallet.publicKey, share: 100 }],
           merkleTree: merkleTreeId,
           releaseAuthority: null,
+          randomnessSource: { slotHashes: undefined },
+          selectionMode: { random: undefined },
+          hiddenSettings: null,
         },
         mintPhases: [
           {
@@ -100,9 +103,19 @@ test("Init mint config", async () => {
             endCondition: null,
             tokenChecks: [],
             authorization: null,
+            priceCurve: null,
+            maxPerWallet: null,
+            maxSupply: null,
+            minted: new BN(0),
+            outputOverrides: null,
+            cooldownSeconds: null,
           },
         ],
         metadata: "",
+        maxPerWalletTotal: null,
+        pauseAuthority: null,
+        phaseSelection: { first: undefined },
+        botTaxLamports: null,
       })
       .accountsStrict({
         mintConfig: findMintConfigId(configName),
@@ -150,6 +163,7 @@ test("Add entry", async () => {
         name: "name1",
         symbol: "sym1",
         uri: "uri1",
+        weight: null,
       })
       .accountsStrict({
         mintConfig: findMintConfigId(configName),
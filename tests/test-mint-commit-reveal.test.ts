@@ -0,0 +1,299 @@
+import { executeTransaction, findMintMetadataId } from "@cardinal/common";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import { beforeAll, test } from "@jest/globals";
+import { Metadata } from "@metaplex-foundation/mpl-token-metadata";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import type { Connection } from "@solana/web3.js";
+import { SystemProgram, Transaction } from "@solana/web3.js";
+import { BN } from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findMintConfigId,
+  findPendingMintId,
+  mint,
+  mintGeneratorProgram,
+  PENDING_MINT_REVEAL_SLOTS,
+  revealMint,
+} from "../sdk";
+import { getTestConfigName, getTestProvider } from "./utils";
+
+let connection: Connection;
+let wallet: Wallet;
+let lateUser: Wallet;
+const configName = getTestConfigName();
+beforeAll(async () => {
+  const provider = await getTestProvider();
+  connection = provider.connection;
+  wallet = provider.wallet;
+  lateUser = (await getTestProvider()).wallet;
+});
+
+test("Init mint config", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.initMintConfig({
+        authority: wallet.publicKey,
+        name: configName,
+        outputMintConfig: {
+          sellerFeeBasisPoints: 10,
+          tokenStandard: { nonFungible: undefined },
+          collection: null,
+          ruleset: null,
+          creators: [{ address: wallet.publicKey, share: 100 }],
+          merkleTree: null,
+          releaseAuthority: null,
+          randomnessSource: { commitReveal: undefined },
+          selectionMode: { random: undefined },
+          hiddenSettings: null,
+        },
+        mintPhases: [
+          {
+            metadata: `{}`,
+            startCondition: null,
+            endCondition: null,
+            tokenChecks: [],
+            authorization: null,
+            priceCurve: null,
+            maxPerWallet: null,
+            maxSupply: null,
+            minted: new BN(0),
+            outputOverrides: null,
+            cooldownSeconds: null,
+          },
+        ],
+        metadata: "",
+        maxPerWalletTotal: null,
+        pauseAuthority: null,
+        phaseSelection: { first: undefined },
+        botTaxLamports: null,
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(mintConfig.parsed.name).toEqual(configName);
+  expect(
+    mintConfig.parsed.outputMintConfig.randomnessSource.commitReveal
+  ).toBeDefined();
+});
+
+test("Add entries", async () => {
+  const tx = new Transaction();
+  for (let i = 0; i < 2; i++) {
+    tx.add(
+      await mintGeneratorProgram(connection, wallet)
+        .methods.setMintEntry({
+          index: new BN(i),
+          name: `name${i}`,
+          symbol: `sym${i}`,
+          uri: `uri${i}`,
+          weight: null,
+        })
+        .accountsStrict({
+          mintConfig: findMintConfigId(configName),
+          authority: wallet.publicKey,
+          payer: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction()
+    );
+  }
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(Number(mintConfig.parsed.supply)).toEqual(2);
+});
+
+test("Mint commits", async () => {
+  const [tx, outputMintKeypair] = await mint(
+    connection,
+    wallet,
+    findMintConfigId(configName),
+    0
+  );
+  expect(outputMintKeypair).toBeNull();
+  await executeTransaction(connection, tx, wallet);
+
+  // check pending mint
+  const pendingMint = await fetchIdlAccount(
+    connection,
+    findPendingMintId(findMintConfigId(configName), wallet.publicKey),
+    "pendingMint"
+  );
+  expect(pendingMint.parsed.user.toString()).toEqual(
+    wallet.publicKey.toString()
+  );
+  expect(pendingMint.parsed.payer.toString()).toEqual(
+    wallet.publicKey.toString()
+  );
+  expect(pendingMint.parsed.vrfRandomness).toBeNull();
+
+  // check token is reserved
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(Number(mintConfig.parsed.count)).toEqual(0);
+  expect(Number(mintConfig.parsed.pendingReveals)).toEqual(1);
+});
+
+test("Mint again before reveal fails", async () => {
+  const [tx] = await mint(connection, wallet, findMintConfigId(configName), 0);
+  await expect(
+    executeTransaction(connection, tx, wallet, { silent: true })
+  ).rejects.toThrow();
+});
+
+test("Reveal", async () => {
+  const pendingMintId = findPendingMintId(
+    findMintConfigId(configName),
+    wallet.publicKey
+  );
+  const pendingMint = await fetchIdlAccount(
+    connection,
+    pendingMintId,
+    "pendingMint"
+  );
+
+  // the commit slot hash exists once the next slot starts
+  while (
+    (await connection.getSlot()) <= Number(pendingMint.parsed.commitSlot)
+  ) {
+    await new Promise((resolve) => setTimeout(resolve, 200));
+  }
+  const [tx, outputMintKeypair] = await revealMint(
+    connection,
+    wallet,
+    findMintConfigId(configName),
+    wallet.publicKey
+  );
+  if (!outputMintKeypair) throw "No output mint keypair";
+  await executeTransaction(connection, tx, wallet, {
+    signers: [outputMintKeypair],
+  });
+
+  // check pending mint is closed
+  const pendingMintInfo = await connection.getAccountInfo(pendingMintId);
+  expect(pendingMintInfo).toBeNull();
+
+  // check data
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(Number(mintConfig.parsed.supply)).toEqual(2);
+  expect(Number(mintConfig.parsed.count)).toEqual(1);
+  expect(Number(mintConfig.parsed.pendingReveals)).toEqual(0);
+
+  // check user account
+  const outputMintUserTokenAccount = await getAccount(
+    connection,
+    getAssociatedTokenAddressSync(outputMintKeypair.publicKey, wallet.publicKey)
+  );
+  expect(Number(outputMintUserTokenAccount.amount)).toEqual(1);
+
+  // check output mint metadata
+  const outputMintMetadata = await Metadata.fromAccountAddress(
+    connection,
+    findMintMetadataId(outputMintKeypair.publicKey)
+  );
+  expect(["name0", "name1"]).toContain(
+    outputMintMetadata.data.name.replace(/\0/g, "")
+  );
+});
+
+test("Reveal after the reveal window fails", async () => {
+  const [commitTx] = await mint(
+    connection,
+    lateUser,
+    findMintConfigId(configName),
+    0
+  );
+  await executeTransaction(connection, commitTx, lateUser);
+  const pendingMint = await fetchIdlAccount(
+    connection,
+    findPendingMintId(findMintConfigId(configName), lateUser.publicKey),
+    "pendingMint"
+  );
+  expect(Number(pendingMint.parsed.revealSlot)).toEqual(
+    Number(pendingMint.parsed.commitSlot) + 1
+  );
+
+  // wait out the reveal window
+  while (
+    (await connection.getSlot()) <=
+    Number(pendingMint.parsed.revealSlot) + PENDING_MINT_REVEAL_SLOTS
+  ) {
+    await new Promise((resolve) => setTimeout(resolve, 400));
+  }
+  const [tx, outputMintKeypair] = await revealMint(
+    connection,
+    lateUser,
+    findMintConfigId(configName),
+    lateUser.publicKey
+  );
+  if (!outputMintKeypair) throw "No output mint keypair";
+  await expect(
+    executeTransaction(connection, tx, lateUser, {
+      signers: [outputMintKeypair],
+      silent: true,
+    })
+  ).rejects.toThrow();
+}, 60000);
+
+test("Expire forfeits the commit", async () => {
+  const pendingMintId = findPendingMintId(
+    findMintConfigId(configName),
+    lateUser.publicKey
+  );
+  const pendingMintInfo = await connection.getAccountInfo(pendingMintId);
+  const authorityBalanceBefore = await connection.getBalance(
+    wallet.publicKey
+  );
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, lateUser)
+      .methods.expirePendingMint()
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        pendingMint: pendingMintId,
+        collector: wallet.publicKey,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, lateUser);
+
+  // check the rent goes to the collector
+  const authorityBalanceAfter = await connection.getBalance(wallet.publicKey);
+  expect(authorityBalanceAfter - authorityBalanceBefore).toEqual(
+    pendingMintInfo?.lamports
+  );
+  expect(await connection.getAccountInfo(pendingMintId)).toBeNull();
+
+  // check the token is released but the phase supply stays used
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(Number(mintConfig.parsed.count)).toEqual(1);
+  expect(Number(mintConfig.parsed.pendingReveals)).toEqual(0);
+  expect(Number(mintConfig.parsed.mintPhases[0]!.minted)).toEqual(2);
+});
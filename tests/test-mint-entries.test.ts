@@ -0,0 +1,332 @@
+import { executeTransaction } from "@cardinal/common";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import { beforeAll, test } from "@jest/globals";
+import type { Connection } from "@solana/web3.js";
+import { SystemProgram, Transaction } from "@solana/web3.js";
+import { BN } from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findMintConfigId,
+  mint,
+  MINT_ENTRY_SIZE,
+  MINT_ENTRY_WEIGHT_BLOCK_SUM_LENGTH,
+  mintGeneratorProgram,
+} from "../sdk";
+import {
+  getTestConfigName,
+  getTestProvider,
+  simulateReturnData,
+} from "./utils";
+
+let connection: Connection;
+let wallet: Wallet;
+const configName = getTestConfigName();
+beforeAll(async () => {
+  const provider = await getTestProvider();
+  connection = provider.connection;
+  wallet = provider.wallet;
+});
+
+const getMintEntry = async (index: number) => {
+  const program = mintGeneratorProgram(connection, wallet);
+  const data = await simulateReturnData(
+    connection,
+    wallet,
+    await program.methods
+      .getMintEntry({ index: new BN(index) })
+      .accountsStrict({ mintConfig: findMintConfigId(configName) })
+      .instruction()
+  );
+  return program.coder.types.decode("MintEntry", data);
+};
+
+const getRemainingSupply = async () => {
+  const program = mintGeneratorProgram(connection, wallet);
+  const data = await simulateReturnData(
+    connection,
+    wallet,
+    await program.methods
+      .getRemainingSupply()
+      .accountsStrict({ mintConfig: findMintConfigId(configName) })
+      .instruction()
+  );
+  return program.coder.types.decode("RemainingSupply", data);
+};
+
+test("Init mint config", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.initMintConfig({
+        authority: wallet.publicKey,
+        name: configName,
+        outputMintConfig: {
+          sellerFeeBasisPoints: 10,
+          tokenStandard: { nonFungible: undefined },
+          collection: null,
+          ruleset: null,
+          creators: [{ address: wallet.publicKey, share: 100 }],
+          merkleTree: null,
+          releaseAuthority: null,
+          randomnessSource: { slotHashes: undefined },
+          selectionMode: { random: undefined },
+          hiddenSettings: null,
+        },
+        mintPhases: [
+          {
+            metadata: `{}`,
+            startCondition: null,
+            endCondition: null,
+            tokenChecks: [],
+            authorization: null,
+            priceCurve: null,
+            maxPerWallet: null,
+            maxSupply: null,
+            minted: new BN(0),
+            outputOverrides: null,
+            cooldownSeconds: null,
+          },
+        ],
+        metadata: "",
+        maxPerWalletTotal: null,
+        pauseAuthority: null,
+        phaseSelection: { first: undefined },
+        botTaxLamports: null,
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(mintConfig.parsed.name).toEqual(configName);
+  expect(Number(mintConfig.parsed.supply)).toEqual(0);
+});
+
+test("Set entries", async () => {
+  const mintConfigAccountBefore = await connection.getAccountInfo(
+    findMintConfigId(configName)
+  );
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.setMintEntries({
+        startIndex: new BN(0),
+        entries: [0, 1, 2, 3, 4].map((i) => ({
+          name: `name${i}`,
+          symbol: `sym${i}`,
+          uri: `uri${i}`,
+          weight: i + 1,
+        })),
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+
+  // check resize
+  expect(mintConfig.data.length).toEqual(
+    mintConfigAccountBefore!.data.length +
+      5 * MINT_ENTRY_SIZE +
+      MINT_ENTRY_WEIGHT_BLOCK_SUM_LENGTH
+  );
+
+  // check data
+  expect(Number(mintConfig.parsed.supply)).toEqual(5);
+  expect(Number(mintConfig.parsed.totalWeight)).toEqual(15);
+  expect(Number(mintConfig.parsed.count)).toEqual(0);
+});
+
+test("Replace and extend entries", async () => {
+  const mintConfigAccountBefore = await connection.getAccountInfo(
+    findMintConfigId(configName)
+  );
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.setMintEntries({
+        startIndex: new BN(3),
+        entries: [3, 4, 5].map((i) => ({
+          name: `replaced${i}`,
+          symbol: `sym${i}`,
+          uri: `uri${i}`,
+          weight: null,
+        })),
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+
+  // check resize - only the new entry is added
+  expect(mintConfig.data.length).toEqual(
+    mintConfigAccountBefore!.data.length + MINT_ENTRY_SIZE
+  );
+
+  // check data
+  expect(Number(mintConfig.parsed.supply)).toEqual(6);
+  expect(Number(mintConfig.parsed.totalWeight)).toEqual(1 + 2 + 3 + 3);
+  const mintEntry = await getMintEntry(3);
+  expect(mintEntry.name).toEqual("replaced3");
+  expect(mintEntry.weight).toEqual(1);
+});
+
+test("Set entries leaving a gap fails", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.setMintEntries({
+        startIndex: new BN(7),
+        entries: [{ name: "name7", symbol: "sym7", uri: "uri7", weight: null }],
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await expect(
+    executeTransaction(connection, tx, wallet, { silent: true })
+  ).rejects.toThrow();
+});
+
+test("Remove entries", async () => {
+  const mintConfigAccountBefore = await connection.getAccountInfo(
+    findMintConfigId(configName)
+  );
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.removeMintEntries({
+        startIndex: new BN(1),
+        length: new BN(2),
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+
+  // check resize
+  expect(mintConfig.data.length).toEqual(
+    mintConfigAccountBefore!.data.length - 2 * MINT_ENTRY_SIZE
+  );
+
+  // check data - the entries after the removed ones move up in order
+  expect(Number(mintConfig.parsed.supply)).toEqual(4);
+  expect(Number(mintConfig.parsed.totalWeight)).toEqual(1 + 1 + 1 + 1);
+  const names = [];
+  for (let i = 0; i < 4; i++) {
+    names.push((await getMintEntry(i)).name);
+  }
+  expect(names).toEqual(["name0", "replaced3", "replaced4", "replaced5"]);
+});
+
+test("Remove entries past supply fails", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.removeMintEntries({
+        startIndex: new BN(3),
+        length: new BN(2),
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await expect(
+    executeTransaction(connection, tx, wallet, { silent: true })
+  ).rejects.toThrow();
+});
+
+test("Get remaining supply", async () => {
+  const remainingSupply = await getRemainingSupply();
+  expect(Number(remainingSupply.supply)).toEqual(4);
+  expect(Number(remainingSupply.count)).toEqual(0);
+  expect(Number(remainingSupply.pendingReveals)).toEqual(0);
+  expect(Number(remainingSupply.available)).toEqual(4);
+});
+
+test("Get mint entry past remaining entries fails", async () => {
+  await expect(getMintEntry(4)).rejects.toBeDefined();
+});
+
+test("Mint", async () => {
+  const [tx, outputMintKeypair] = await mint(
+    connection,
+    wallet,
+    findMintConfigId(configName),
+    0
+  );
+  if (!outputMintKeypair) throw "No output mint keypair";
+  await executeTransaction(connection, tx, wallet, {
+    signers: [outputMintKeypair],
+  });
+
+  const remainingSupply = await getRemainingSupply();
+  expect(Number(remainingSupply.supply)).toEqual(4);
+  expect(Number(remainingSupply.count)).toEqual(1);
+  expect(Number(remainingSupply.available)).toEqual(3);
+});
+
+test("Remove entries after minting fails", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.removeMintEntries({
+        startIndex: new BN(0),
+        length: new BN(1),
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await expect(
+    executeTransaction(connection, tx, wallet, { silent: true })
+  ).rejects.toThrow();
+});
@@ -0,0 +1,317 @@
+import { executeTransaction, findMintMetadataId } from "@cardinal/common";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import { beforeAll, test } from "@jest/globals";
+import { Metadata } from "@metaplex-foundation/mpl-token-metadata";
+import type { Connection } from "@solana/web3.js";
+import { SystemProgram, Transaction } from "@solana/web3.js";
+import { BN } from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findMintConfigId,
+  findMintEntryPageId,
+  mint,
+  MINT_ENTRY_PAGE_CAPACITY,
+  mintGeneratorProgram,
+} from "../sdk";
+import {
+  getTestConfigName,
+  getTestProvider,
+  simulateReturnData,
+} from "./utils";
+
+let connection: Connection;
+let wallet: Wallet;
+const configName = getTestConfigName();
+beforeAll(async () => {
+  const provider = await getTestProvider();
+  connection = provider.connection;
+  wallet = provider.wallet;
+});
+
+test("Init mint config", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.initMintConfig({
+        authority: wallet.publicKey,
+        name: configName,
+        outputMintConfig: {
+          sellerFeeBasisPoints: 10,
+          tokenStandard: { nonFungible: undefined },
+          collection: null,
+          ruleset: null,
+          creators: [{ address: wallet.publicKey, share: 100 }],
+          merkleTree: null,
+          releaseAuthority: null,
+          randomnessSource: { slotHashes: undefined },
+          selectionMode: { sequential: undefined },
+          hiddenSettings: null,
+        },
+        mintPhases: [
+          {
+            metadata: `{}`,
+            startCondition: null,
+            endCondition: null,
+            tokenChecks: [],
+            authorization: null,
+            priceCurve: null,
+            maxPerWallet: null,
+            maxSupply: null,
+            minted: new BN(0),
+            outputOverrides: null,
+            cooldownSeconds: null,
+          },
+        ],
+        metadata: "",
+        maxPerWalletTotal: null,
+        pauseAuthority: null,
+        phaseSelection: { first: undefined },
+        botTaxLamports: null,
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(mintConfig.parsed.name).toEqual(configName);
+  expect(mintConfig.parsed.entryStorage.inline).toBeDefined();
+});
+
+test("Init mint entry pages", async () => {
+  const tx = new Transaction();
+  for (let i = 0; i < 2; i++) {
+    tx.add(
+      await mintGeneratorProgram(connection, wallet)
+        .methods.initMintEntryPage({ pageIndex: i })
+        .accountsStrict({
+          mintEntryPage: findMintEntryPageId(findMintConfigId(configName), i),
+          mintConfig: findMintConfigId(configName),
+          authority: wallet.publicKey,
+          payer: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction()
+    );
+  }
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(mintConfig.parsed.entryStorage.paged?.pageRemaining).toEqual([0, 0]);
+
+  // check pages
+  for (let i = 0; i < 2; i++) {
+    const mintEntryPage = await fetchIdlAccount(
+      connection,
+      findMintEntryPageId(findMintConfigId(configName), i),
+      "mintEntryPage"
+    );
+    expect(mintEntryPage.parsed.mintConfig.toString()).toEqual(
+      findMintConfigId(configName).toString()
+    );
+    expect(mintEntryPage.parsed.pageIndex).toEqual(i);
+    expect(mintEntryPage.parsed.supply).toEqual(0);
+  }
+});
+
+test("Init mint entry page out of order fails", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.initMintEntryPage({ pageIndex: 3 })
+      .accountsStrict({
+        mintEntryPage: findMintEntryPageId(findMintConfigId(configName), 3),
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await expect(
+    executeTransaction(connection, tx, wallet, { silent: true })
+  ).rejects.toThrow();
+});
+
+test("Set entries in pages", async () => {
+  const entries = [
+    { startIndex: 0, names: ["name0", "name1"] },
+    { startIndex: MINT_ENTRY_PAGE_CAPACITY, names: ["name2"] },
+  ];
+  const tx = new Transaction();
+  for (const { startIndex, names } of entries) {
+    tx.add(
+      await mintGeneratorProgram(connection, wallet)
+        .methods.setMintEntries({
+          startIndex: new BN(startIndex),
+          entries: names.map((name) => ({
+            name,
+            symbol: "sym",
+            uri: "uri",
+            weight: null,
+          })),
+        })
+        .accountsStrict({
+          mintConfig: findMintConfigId(configName),
+          authority: wallet.publicKey,
+          payer: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          {
+            pubkey: findMintEntryPageId(
+              findMintConfigId(configName),
+              startIndex / MINT_ENTRY_PAGE_CAPACITY
+            ),
+            isWritable: true,
+            isSigner: false,
+          },
+        ])
+        .instruction()
+    );
+  }
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(Number(mintConfig.parsed.supply)).toEqual(3);
+  expect(mintConfig.parsed.entryStorage.paged?.pageRemaining).toEqual([2, 1]);
+
+  // check pages
+  const mintEntryPage = await fetchIdlAccount(
+    connection,
+    findMintEntryPageId(findMintConfigId(configName), 1),
+    "mintEntryPage"
+  );
+  expect(mintEntryPage.parsed.supply).toEqual(1);
+  expect(mintEntryPage.parsed.count).toEqual(0);
+});
+
+test("Set weighted entries in pages fails", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.setMintEntries({
+        startIndex: new BN(2),
+        entries: [{ name: "name", symbol: "sym", uri: "uri", weight: 2 }],
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts([
+        {
+          pubkey: findMintEntryPageId(findMintConfigId(configName), 0),
+          isWritable: true,
+          isSigner: false,
+        },
+      ])
+      .instruction()
+  );
+  await expect(
+    executeTransaction(connection, tx, wallet, { silent: true })
+  ).rejects.toThrow();
+});
+
+test("Get mint entry from page", async () => {
+  const program = mintGeneratorProgram(connection, wallet);
+  const data = await simulateReturnData(
+    connection,
+    wallet,
+    await program.methods
+      .getMintEntry({ index: new BN(2) })
+      .accountsStrict({ mintConfig: findMintConfigId(configName) })
+      .remainingAccounts([
+        {
+          pubkey: findMintEntryPageId(findMintConfigId(configName), 1),
+          isWritable: false,
+          isSigner: false,
+        },
+      ])
+      .instruction()
+  );
+  const mintEntry = program.coder.types.decode("MintEntry", data);
+  expect(mintEntry.name).toEqual("name2");
+});
+
+test("Mint with wrong page fails", async () => {
+  const [tx, outputMintKeypair] = await mint(
+    connection,
+    wallet,
+    findMintConfigId(configName),
+    0,
+    { mintEntryPageIndex: 1 }
+  );
+  if (!outputMintKeypair) throw "No output mint keypair";
+  await expect(
+    executeTransaction(connection, tx, wallet, {
+      signers: [outputMintKeypair],
+      silent: true,
+    })
+  ).rejects.toThrow();
+});
+
+test("Mint 3", async () => {
+  for (let i = 0; i < 3; i++) {
+    const [tx, outputMintKeypair] = await mint(
+      connection,
+      wallet,
+      findMintConfigId(configName),
+      0
+    );
+    if (!outputMintKeypair) throw "No output mint keypair";
+    await executeTransaction(connection, tx, wallet, {
+      signers: [outputMintKeypair],
+    });
+
+    // check entries are minted in order across pages
+    const outputMintMetadata = await Metadata.fromAccountAddress(
+      connection,
+      findMintMetadataId(outputMintKeypair.publicKey)
+    );
+    expect(outputMintMetadata.data.name.replace(/\0/g, "")).toEqual(
+      `name${i}`
+    );
+  }
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(Number(mintConfig.parsed.count)).toEqual(3);
+  expect(mintConfig.parsed.entryStorage.paged?.pageRemaining).toEqual([0, 0]);
+});
+
+test("Close mint entry page", async () => {
+  const mintEntryPageId = findMintEntryPageId(findMintConfigId(configName), 0);
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.closeMintEntryPage()
+      .accountsStrict({
+        mintEntryPage: mintEntryPageId,
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, wallet);
+  const mintEntryPageInfo = await connection.getAccountInfo(mintEntryPageId);
+  expect(mintEntryPageInfo).toBeNull();
+});
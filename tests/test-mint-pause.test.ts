@@ -0,0 +1,278 @@
+import { executeTransaction } from "@cardinal/common";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import { beforeAll, test } from "@jest/globals";
+import type { Connection } from "@solana/web3.js";
+import { SystemProgram, Transaction } from "@solana/web3.js";
+import { BN } from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findMintConfigId,
+  findMintCounterId,
+  mint,
+  mintGeneratorProgram,
+} from "../sdk";
+import {
+  getTestConfigName,
+  getTestProvider,
+  simulateReturnData,
+} from "./utils";
+
+let connection: Connection;
+let wallet: Wallet;
+let pauseAuthority: Wallet;
+const configName = getTestConfigName();
+beforeAll(async () => {
+  const provider = await getTestProvider();
+  connection = provider.connection;
+  wallet = provider.wallet;
+  pauseAuthority = (await getTestProvider()).wallet;
+});
+
+// active phases are returned as a borsh encoded Vec<ActivePhase>
+const getActivePhases = async () => {
+  const data = await simulateReturnData(
+    connection,
+    wallet,
+    await mintGeneratorProgram(connection, wallet)
+      .methods.getActivePhases({ user: wallet.publicKey })
+      .accountsStrict({ mintConfig: findMintConfigId(configName) })
+      .remainingAccounts([
+        {
+          pubkey: findMintCounterId(
+            findMintConfigId(configName),
+            0,
+            wallet.publicKey
+          ),
+          isWritable: false,
+          isSigner: false,
+        },
+      ])
+      .instruction()
+  );
+  const activePhases = [];
+  let offset = 4;
+  for (let i = 0; i < data.readUInt32LE(0); i++) {
+    const mintPhaseIndex = data.readUInt8(offset);
+    const hasRemaining = data.readUInt8(offset + 1) === 1;
+    activePhases.push({
+      mintPhaseIndex,
+      remaining: hasRemaining ? Number(data.readBigUInt64LE(offset + 2)) : null,
+    });
+    offset += hasRemaining ? 10 : 2;
+  }
+  return activePhases;
+};
+
+test("Init mint config", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.initMintConfig({
+        authority: wallet.publicKey,
+        name: configName,
+        outputMintConfig: {
+          sellerFeeBasisPoints: 10,
+          tokenStandard: { nonFungible: undefined },
+          collection: null,
+          ruleset: null,
+          creators: [{ address: wallet.publicKey, share: 100 }],
+          merkleTree: null,
+          releaseAuthority: null,
+          randomnessSource: { slotHashes: undefined },
+          selectionMode: { random: undefined },
+          hiddenSettings: null,
+        },
+        mintPhases: [
+          {
+            metadata: `{}`,
+            startCondition: null,
+            endCondition: null,
+            tokenChecks: [],
+            authorization: null,
+            priceCurve: null,
+            maxPerWallet: new BN(2),
+            maxSupply: null,
+            minted: new BN(0),
+            outputOverrides: null,
+            cooldownSeconds: null,
+          },
+          {
+            metadata: `{}`,
+            startCondition: {
+              time: {
+                seconds: new BN(Math.floor(Date.now() / 1000) + 60 * 60),
+              },
+            },
+            endCondition: null,
+            tokenChecks: [],
+            authorization: null,
+            priceCurve: null,
+            maxPerWallet: null,
+            maxSupply: null,
+            minted: new BN(0),
+            outputOverrides: null,
+            cooldownSeconds: null,
+          },
+        ],
+        metadata: "",
+        maxPerWalletTotal: null,
+        pauseAuthority: pauseAuthority.publicKey,
+        phaseSelection: { first: undefined },
+        botTaxLamports: null,
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(mintConfig.parsed.name).toEqual(configName);
+  expect(mintConfig.parsed.pauseAuthority?.toString()).toEqual(
+    pauseAuthority.publicKey.toString()
+  );
+  expect(mintConfig.parsed.paused).toEqual(false);
+});
+
+test("Add entries", async () => {
+  const tx = new Transaction();
+  for (let i = 0; i < 2; i++) {
+    tx.add(
+      await mintGeneratorProgram(connection, wallet)
+        .methods.setMintEntry({
+          index: new BN(i),
+          name: `name${i}`,
+          symbol: `sym${i}`,
+          uri: `uri${i}`,
+          weight: null,
+        })
+        .accountsStrict({
+          mintConfig: findMintConfigId(configName),
+          authority: wallet.publicKey,
+          payer: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction()
+    );
+  }
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(Number(mintConfig.parsed.supply)).toEqual(2);
+});
+
+test("Get active phases", async () => {
+  const activePhases = await getActivePhases();
+  expect(activePhases).toEqual([{ mintPhaseIndex: 0, remaining: 2 }]);
+});
+
+test("Pause by other wallet fails", async () => {
+  const otherWallet = (await getTestProvider()).wallet;
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, otherWallet)
+      .methods.pauseMint()
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: otherWallet.publicKey,
+      })
+      .instruction()
+  );
+  await expect(
+    executeTransaction(connection, tx, otherWallet, { silent: true })
+  ).rejects.toThrow();
+});
+
+test("Pause", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, pauseAuthority)
+      .methods.pauseMint()
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: pauseAuthority.publicKey,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, pauseAuthority);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(mintConfig.parsed.paused).toEqual(true);
+
+  // no phase is active while paused
+  const activePhases = await getActivePhases();
+  expect(activePhases).toEqual([]);
+});
+
+test("Mint while paused fails", async () => {
+  const [tx, outputMintKeypair] = await mint(
+    connection,
+    wallet,
+    findMintConfigId(configName),
+    0
+  );
+  if (!outputMintKeypair) throw "No output mint keypair";
+  await expect(
+    executeTransaction(connection, tx, wallet, {
+      signers: [outputMintKeypair],
+      silent: true,
+    })
+  ).rejects.toThrow();
+});
+
+test("Resume", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, pauseAuthority)
+      .methods.resumeMint()
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: pauseAuthority.publicKey,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, pauseAuthority);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(mintConfig.parsed.paused).toEqual(false);
+});
+
+test("Mint after resume", async () => {
+  const [tx, outputMintKeypair] = await mint(
+    connection,
+    wallet,
+    findMintConfigId(configName),
+    0
+  );
+  if (!outputMintKeypair) throw "No output mint keypair";
+  await executeTransaction(connection, tx, wallet, {
+    signers: [outputMintKeypair],
+  });
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(Number(mintConfig.parsed.count)).toEqual(1);
+
+  // check the wallet limit is counted
+  const activePhases = await getActivePhases();
+  expect(activePhases).toEqual([{ mintPhaseIndex: 0, remaining: 1 }]);
+});
@@ -75,6 +75,9 @@ test("Init mint config", async () => {
           creators: [{ address: wallet.publicKey, share: 100 }],
           merkleTree: null,
           releaseAuthority: null,
+          randomnessSource: { slotHashes: undefined },
+          selectionMode: { random: undefined },
+          hiddenSettings: null,
         },
         mintPhases: [
           {
@@ -93,9 +96,19 @@ test("Init mint config", async () => {
             authorization: {
               mode: { defaultDisallowed: undefined },
             },
+            priceCurve: null,
+            maxPerWallet: null,
+            maxSupply: null,
+            minted: new BN(0),
+            outputOverrides: null,
+            cooldownSeconds: null,
           },
         ],
         metadata: "",
+        maxPerWalletTotal: null,
+        pauseAuthority: null,
+        phaseSelection: { first: undefined },
+        botTaxLamports: null,
       })
       .accountsStrict({
         mintConfig: mintConfigId,
@@ -134,6 +147,7 @@ test("Add entry", async () => {
         name: "name1",
         symbol: "sym1",
         uri: "uri1",
+        weight: null,
       })
       .accountsStrict({
         mintConfig: mintConfigId,
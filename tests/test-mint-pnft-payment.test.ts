@@ -65,6 +65,9 @@ test("Init mint config", async () => {
           creators: [{ address: wallet.publicKey, share: 100 }],
           merkleTree: null,
           releaseAuthority: null,
+          randomnessSource: { slotHashes: undefined },
+          selectionMode: { random: undefined },
+          hiddenSettings: null,
         },
         mintPhases: [
           {
@@ -81,9 +84,19 @@ test("Init mint config", async () => {
               },
             ],
             authorization: null,
+            priceCurve: null,
+            maxPerWallet: null,
+            maxSupply: null,
+            minted: new BN(0),
+            outputOverrides: null,
+            cooldownSeconds: null,
           },
         ],
         metadata: "",
+        maxPerWalletTotal: null,
+        pauseAuthority: null,
+        phaseSelection: { first: undefined },
+        botTaxLamports: null,
       })
       .accountsStrict({
         mintConfig: findMintConfigId(configName),
@@ -122,6 +135,7 @@ test("Add entry", async () => {
         name: "name1",
         symbol: "sym1",
         uri: "uri1",
+        weight: null,
       })
       .accountsStrict({
         mintConfig: findMintConfigId(configName),
@@ -36,9 +36,16 @@ test("Init mint config", async () => {
           creators: [],
           merkleTree: null,
           releaseAuthority: null,
+          randomnessSource: { slotHashes: undefined },
+          selectionMode: { random: undefined },
+          hiddenSettings: null,
         },
         mintPhases: [],
         metadata: "",
+        maxPerWalletTotal: null,
+        pauseAuthority: null,
+        phaseSelection: { first: undefined },
+        botTaxLamports: null,
       })
       .accountsStrict({
         mintConfig: findMintConfigId(configName),
@@ -73,6 +80,7 @@ test("Add entry", async () => {
         name: "name1",
         symbol: "sym1",
         uri: "uri1",
+        weight: null,
       })
       .accountsStrict({
         mintConfig: findMintConfigId(configName),
@@ -105,6 +113,7 @@ test("Add resizing entry", async () => {
         name: "name10",
         symbol: "sym10",
         uri: "uri10",
+        weight: null,
       })
       .accountsStrict({
         mintConfig: findMintConfigId(configName),
@@ -137,6 +146,7 @@ test("Replace entry", async () => {
         name: "name1-1",
         symbol: "sym1-1",
         uri: "uri1-1",
+        weight: null,
       })
       .accountsStrict({
         mintConfig: findMintConfigId(configName),
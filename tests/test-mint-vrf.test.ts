@@ -0,0 +1,373 @@
+import { executeTransaction, findMintMetadataId } from "@cardinal/common";
+import type { Wallet } from "@coral-xyz/anchor/dist/cjs/provider";
+import { beforeAll, test } from "@jest/globals";
+import { Metadata } from "@metaplex-foundation/mpl-token-metadata";
+import { getAccount, getAssociatedTokenAddressSync } from "@solana/spl-token";
+import type { Connection } from "@solana/web3.js";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import { BN } from "bn.js";
+
+import {
+  fetchIdlAccount,
+  findMintConfigId,
+  findPendingMintId,
+  mint,
+  MINT_GENERATOR_PROGRAM_ID,
+  mintGeneratorProgram,
+  revealMint,
+} from "../sdk";
+import {
+  findVrfRandomnessId,
+  getTestConfigName,
+  getTestProvider,
+  MOCK_VRF_ORACLE_PROGRAM_ID,
+  mockVrfOracleProgram,
+} from "./utils";
+
+let connection: Connection;
+let wallet: Wallet;
+const configName = getTestConfigName();
+const randomness = Keypair.generate().publicKey.toBytes();
+beforeAll(async () => {
+  const provider = await getTestProvider();
+  connection = provider.connection;
+  wallet = provider.wallet;
+});
+
+test("Init mint config", async () => {
+  const tx = new Transaction();
+  tx.add(
+    await mintGeneratorProgram(connection, wallet)
+      .methods.initMintConfig({
+        authority: wallet.publicKey,
+        name: configName,
+        outputMintConfig: {
+          sellerFeeBasisPoints: 10,
+          tokenStandard: { nonFungible: undefined },
+          collection: null,
+          ruleset: null,
+          creators: [{ address: wallet.publicKey, share: 100 }],
+          merkleTree: null,
+          releaseAuthority: null,
+          randomnessSource: {
+            vrf: { oracleProgram: MOCK_VRF_ORACLE_PROGRAM_ID },
+          },
+          selectionMode: { random: undefined },
+          hiddenSettings: null,
+        },
+        mintPhases: [
+          {
+            metadata: `{}`,
+            startCondition: null,
+            endCondition: null,
+            tokenChecks: [],
+            authorization: null,
+            priceCurve: null,
+            maxPerWallet: null,
+            maxSupply: null,
+            minted: new BN(0),
+            outputOverrides: null,
+            cooldownSeconds: null,
+          },
+        ],
+        metadata: "",
+        maxPerWalletTotal: null,
+        pauseAuthority: null,
+        phaseSelection: { first: undefined },
+        botTaxLamports: null,
+      })
+      .accountsStrict({
+        mintConfig: findMintConfigId(configName),
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .instruction()
+  );
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(mintConfig.parsed.name).toEqual(configName);
+  const randomnessSource = mintConfig.parsed.outputMintConfig.randomnessSource;
+  expect(randomnessSource.vrf?.oracleProgram.toString()).toEqual(
+    MOCK_VRF_ORACLE_PROGRAM_ID.toString()
+  );
+});
+
+test("Add entries", async () => {
+  const tx = new Transaction();
+  for (let i = 0; i < 2; i++) {
+    tx.add(
+      await mintGeneratorProgram(connection, wallet)
+        .methods.setMintEntry({
+          index: new BN(i),
+          name: `name${i}`,
+          symbol: `sym${i}`,
+          uri: `uri${i}`,
+          weight: null,
+        })
+        .accountsStrict({
+          mintConfig: findMintConfigId(configName),
+          authority: wallet.publicKey,
+          payer: wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .instruction()
+    );
+  }
+  await executeTransaction(connection, tx, wallet);
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(Number(mintConfig.parsed.supply)).toEqual(2);
+  expect(Number(mintConfig.parsed.count)).toEqual(0);
+});
+
+test("Mint requests randomness", async () => {
+  const pendingMintId = findPendingMintId(
+    findMintConfigId(configName),
+    wallet.publicKey
+  );
+  const [tx, outputMintKeypair] = await mint(
+    connection,
+    wallet,
+    findMintConfigId(configName),
+    0,
+    { vrfRandomnessId: findVrfRandomnessId(pendingMintId) }
+  );
+  expect(outputMintKeypair).toBeNull();
+  await executeTransaction(connection, tx, wallet);
+
+  // check pending mint
+  const pendingMint = await fetchIdlAccount(
+    connection,
+    pendingMintId,
+    "pendingMint"
+  );
+  expect(pendingMint.parsed.user.toString()).toEqual(
+    wallet.publicKey.toString()
+  );
+  expect(pendingMint.parsed.vrfRandomness?.toString()).toEqual(
+    findVrfRandomnessId(pendingMintId).toString()
+  );
+  expect(pendingMint.parsed.randomness).toBeNull();
+
+  // check randomness request
+  const oracleProgram = mockVrfOracleProgram(connection, wallet);
+  const vrfRandomness = await oracleProgram.account.vrfRandomness.fetch(
+    findVrfRandomnessId(pendingMintId)
+  );
+  expect(vrfRandomness.requester.toString()).toEqual(pendingMintId.toString());
+  expect(vrfRandomness.seed).toEqual(pendingMint.parsed.commitHash);
+  expect(vrfRandomness.fulfilled).toEqual(false);
+
+  // check token is reserved
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(Number(mintConfig.parsed.count)).toEqual(0);
+  expect(Number(mintConfig.parsed.pendingReveals)).toEqual(1);
+});
+
+test("Reveal fails before randomness is fulfilled", async () => {
+  const [tx, outputMintKeypair] = await revealMint(
+    connection,
+    wallet,
+    findMintConfigId(configName),
+    wallet.publicKey
+  );
+  if (!outputMintKeypair) throw "No output mint keypair";
+  await expect(
+    executeTransaction(connection, tx, wallet, {
+      signers: [outputMintKeypair],
+      silent: true,
+    })
+  ).rejects.toThrow();
+});
+
+test("Fulfill randomness", async () => {
+  const pendingMintId = findPendingMintId(
+    findMintConfigId(configName),
+    wallet.publicKey
+  );
+  const tx = new Transaction();
+  tx.add(
+    await mockVrfOracleProgram(connection, wallet)
+      .methods.fulfillRandomness(Array.from(randomness))
+      .accountsStrict({
+        vrfRandomness: findVrfRandomnessId(pendingMintId),
+        requester: pendingMintId,
+        callbackProgram: MINT_GENERATOR_PROGRAM_ID,
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+      })
+      .remainingAccounts([
+        {
+          pubkey: findMintConfigId(configName),
+          isWritable: false,
+          isSigner: false,
+        },
+      ])
+      .instruction()
+  );
+  await executeTransaction(connection, tx, wallet);
+
+  // check callback stored the randomness
+  const pendingMint = await fetchIdlAccount(
+    connection,
+    pendingMintId,
+    "pendingMint"
+  );
+  expect(pendingMint.parsed.randomness).toEqual(Array.from(randomness));
+
+  // check randomness account is closed
+  const vrfRandomnessInfo = await connection.getAccountInfo(
+    findVrfRandomnessId(pendingMintId)
+  );
+  expect(vrfRandomnessInfo).toBeNull();
+});
+
+test("Fulfill randomness twice fails", async () => {
+  const pendingMintId = findPendingMintId(
+    findMintConfigId(configName),
+    wallet.publicKey
+  );
+  const tx = new Transaction();
+  tx.add(
+    await mockVrfOracleProgram(connection, wallet)
+      .methods.fulfillRandomness(
+        Array.from(Keypair.generate().publicKey.toBytes())
+      )
+      .accountsStrict({
+        vrfRandomness: findVrfRandomnessId(pendingMintId),
+        requester: pendingMintId,
+        callbackProgram: MINT_GENERATOR_PROGRAM_ID,
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+      })
+      .remainingAccounts([
+        {
+          pubkey: findMintConfigId(configName),
+          isWritable: false,
+          isSigner: false,
+        },
+      ])
+      .instruction()
+  );
+  await expect(
+    executeTransaction(connection, tx, wallet, { silent: true })
+  ).rejects.toThrow();
+});
+
+test("Reveal", async () => {
+  const pendingMintId = findPendingMintId(
+    findMintConfigId(configName),
+    wallet.publicKey
+  );
+  const [tx, outputMintKeypair] = await revealMint(
+    connection,
+    wallet,
+    findMintConfigId(configName),
+    wallet.publicKey
+  );
+  if (!outputMintKeypair) throw "No output mint keypair";
+  await executeTransaction(connection, tx, wallet, {
+    signers: [outputMintKeypair],
+  });
+
+  // check pending mint is closed
+  const pendingMintInfo = await connection.getAccountInfo(pendingMintId);
+  expect(pendingMintInfo).toBeNull();
+
+  // check data
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(Number(mintConfig.parsed.count)).toEqual(1);
+  expect(Number(mintConfig.parsed.pendingReveals)).toEqual(0);
+
+  // check user account
+  const outputMintUserTokenAccount = await getAccount(
+    connection,
+    getAssociatedTokenAddressSync(outputMintKeypair.publicKey, wallet.publicKey)
+  );
+  expect(Number(outputMintUserTokenAccount.amount)).toEqual(1);
+
+  // check output mint metadata
+  const outputMintMetadata = await Metadata.fromAccountAddress(
+    connection,
+    findMintMetadataId(outputMintKeypair.publicKey)
+  );
+  expect(["name0", "name1"]).toContain(
+    outputMintMetadata.data.name.replace(/\0/g, "")
+  );
+});
+
+test("Mint again with the same wallet", async () => {
+  const pendingMintId = findPendingMintId(
+    findMintConfigId(configName),
+    wallet.publicKey
+  );
+  const [tx] = await mint(
+    connection,
+    wallet,
+    findMintConfigId(configName),
+    0,
+    { vrfRandomnessId: findVrfRandomnessId(pendingMintId) }
+  );
+  await executeTransaction(connection, tx, wallet);
+
+  // the first randomness account was closed so it can be requested again
+  const fulfillTx = new Transaction();
+  fulfillTx.add(
+    await mockVrfOracleProgram(connection, wallet)
+      .methods.fulfillRandomness(
+        Array.from(Keypair.generate().publicKey.toBytes())
+      )
+      .accountsStrict({
+        vrfRandomness: findVrfRandomnessId(pendingMintId),
+        requester: pendingMintId,
+        callbackProgram: MINT_GENERATOR_PROGRAM_ID,
+        authority: wallet.publicKey,
+        payer: wallet.publicKey,
+      })
+      .remainingAccounts([
+        {
+          pubkey: findMintConfigId(configName),
+          isWritable: false,
+          isSigner: false,
+        },
+      ])
+      .instruction()
+  );
+  await executeTransaction(connection, fulfillTx, wallet);
+
+  const [revealTx, outputMintKeypair] = await revealMint(
+    connection,
+    wallet,
+    findMintConfigId(configName),
+    wallet.publicKey
+  );
+  if (!outputMintKeypair) throw "No output mint keypair";
+  await executeTransaction(connection, revealTx, wallet, {
+    signers: [outputMintKeypair],
+  });
+
+  // check both entries are minted
+  const mintConfig = await fetchIdlAccount(
+    connection,
+    findMintConfigId(configName),
+    "mintConfig"
+  );
+  expect(Number(mintConfig.parsed.count)).toEqual(2);
+  expect(Number(mintConfig.parsed.pendingReveals)).toEqual(0);
+});
@@ -7,7 +7,7 @@ import {
   getTestConnection,
   newAccountWithLamports,
 } from "@cardinal/common";
-import { Wallet } from "@coral-xyz/anchor";
+import { AnchorProvider, Program, utils, Wallet } from "@coral-xyz/anchor";
 import { createCreateOrUpdateInstruction } from "@metaplex-foundation/mpl-token-auth-rules";
 import {
   createCreateMasterEditionV3Instruction,
@@ -24,18 +24,17 @@ import {
   MINT_SIZE,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
-import type {
-  Connection,
-  PublicKey,
-  TransactionInstruction,
-} from "@solana/web3.js";
+import type { Connection, TransactionInstruction } from "@solana/web3.js";
 import {
+  PublicKey,
   SystemProgram,
   SYSVAR_INSTRUCTIONS_PUBKEY,
   Transaction,
 } from "@solana/web3.js";
 
 import { findCollectionDelegateId } from "../sdk";
+import type { MockVrfOracle } from "../sdk/idl/mock_vrf_oracle";
+import { IDL as MOCK_VRF_ORACLE_IDL } from "../sdk/idl/mock_vrf_oracle";
 
 export async function getTestProvider(
   lamports?: number
@@ -53,6 +52,44 @@ export const getTestConfigName = () => {
   return `test-${Math.floor(Math.random() * 100000)}`;
 };
 
+export const MOCK_VRF_ORACLE_PROGRAM_ID = new PublicKey(
+  "VrfMnhARw9B4TC7Zr2y7TMwAHoY2uhou6x3odkdJA8P"
+);
+
+export const mockVrfOracleProgram = (
+  connection: Connection,
+  wallet: Wallet
+) => {
+  return new Program<MockVrfOracle>(
+    MOCK_VRF_ORACLE_IDL,
+    MOCK_VRF_ORACLE_PROGRAM_ID,
+    new AnchorProvider(connection, wallet, {})
+  );
+};
+
+export const findVrfRandomnessId = (requester: PublicKey): PublicKey => {
+  return PublicKey.findProgramAddressSync(
+    [utils.bytes.utf8.encode("vrf-randomness"), requester.toBuffer()],
+    MOCK_VRF_ORACLE_PROGRAM_ID
+  )[0];
+};
+
+// data set by a view instruction with set_return_data
+export const simulateReturnData = async (
+  connection: Connection,
+  wallet: Wallet,
+  ix: TransactionInstruction
+): Promise<Buffer> => {
+  const tx = new Transaction().add(ix);
+  tx.feePayer = wallet.publicKey;
+  tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
+  const simulation = await connection.simulateTransaction(tx);
+  if (simulation.value.err) throw simulation.value.err;
+  const returnData = simulation.value.returnData;
+  if (!returnData) throw "No return data";
+  return Buffer.from(returnData.data[0], "base64");
+};
+
 export const createRulesetIx = (
   provider: CardinalProvider
 ): [TransactionInstruction, PublicKey] => {